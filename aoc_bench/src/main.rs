@@ -0,0 +1,86 @@
+/*!
+# Advent of Code 2020 - Benchmarks
+
+Measures how long parsing and solving takes for each day. The solvers are
+run against the hard-coded test data, so network is not used.
+
+## Usage example
+
+```text ignore
+PS> cargo run --release --bin aoc_bench
+Advent of Code 2020 - Benchmarks
+Day 01 parse: 261ns
+Day 01 part 1: 122ns
+Day 01 part 2: 96ns
+...
+```
+!*/
+
+use std::hint::black_box;
+use std::time::Instant;
+
+static ITERATIONS: u32 = 1000;
+
+/// Run `f` many times and print the average time of one run.
+fn bench<T>(name: &str, mut f: impl FnMut() -> T) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    println!("{}: {:?}", name, start.elapsed() / ITERATIONS);
+}
+
+fn main() {
+    println!("Advent of Code 2020 - Benchmarks");
+
+    let input = day_01::get_input_test();
+    bench("Day 01 parse", || day_01::parse_numbers(&input));
+    let numbers = day_01::parse_numbers(&input);
+    bench("Day 01 part 1", || day_01::day_01(numbers.clone(), 2020));
+    bench("Day 01 part 1 two pointer", || {
+        day_01::day_01_two_pointer(numbers.clone(), 2020)
+    });
+    bench("Day 01 part 2", || day_01::day_01_part2(numbers.clone()));
+
+    let input: Vec<String> = day_02::get_input_test()
+        .lines()
+        .map(|s| s.trim().to_string())
+        .collect();
+    bench("Day 02 parse", || day_02::parse_input(&input));
+    let passwords = day_02::parse_input(&input);
+    bench("Day 02 part 1", || {
+        day_02::count_valid_passwords(&passwords, day_02::PolicyMode::CountRange)
+    });
+    bench("Day 02 part 2", || {
+        day_02::count_valid_passwords(&passwords, day_02::PolicyMode::Positions)
+    });
+
+    let input = day_03::get_input_test();
+    bench("Day 03 parse", || {
+        day_03::TobogganMap::from_string_map(&input)
+    });
+    let mut map = day_03::TobogganMap::from_string_map(&input);
+    bench("Day 03 solve", || {
+        let mut product = 1;
+        for (dx, dy) in [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter() {
+            product *= day_03::count_trees(&mut map, *dx, *dy);
+            map.reset_position();
+        }
+        product
+    });
+
+    let input = day_04::get_input_test();
+    bench("Day 04 part 1", || day_04::count_present_passports(&input));
+    bench("Day 04 part 2", || {
+        day_04::parse_string_to_passports(&input)
+    });
+    let many = vec![input; 200].join("\n\n");
+    bench("Day 04 part 2, 800 passports", || {
+        day_04::parse_string_to_passports(&many)
+    });
+
+    let input = day_05::get_input_test();
+    bench("Day 05 parse", || day_05::parse_seats(&input));
+    let seats = day_05::parse_seats(&input);
+    bench("Day 05 solve", || day_05::find_missing_seat(&seats));
+}
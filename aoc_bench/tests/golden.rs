@@ -0,0 +1,65 @@
+//! Runs every day's solver against its hard-coded test data and checks
+//! the answers given in the puzzle descriptions. Network is not used.
+
+use aoc_common::Answer;
+use aoc_runner::sample_answers;
+
+/// Expected answers of both parts for given day.
+fn expected(day: usize) -> (Answer, Answer) {
+    sample_answers()[day - 1].clone()
+}
+
+#[test]
+fn day_01() {
+    let numbers = day_01::parse_numbers(&day_01::get_input_test());
+    let (a, b) = day_01::day_01(numbers.clone(), 2020).unwrap();
+    let (a2, b2, c2) = day_01::day_01_part2(numbers);
+    assert_eq!(
+        (Answer::from(a * b), Answer::from(a2 * b2 * c2)),
+        expected(1)
+    );
+}
+
+#[test]
+fn day_02() {
+    let input: Vec<String> = day_02::get_input_test()
+        .lines()
+        .map(|s| s.trim().to_string())
+        .collect();
+    let passwords = day_02::parse_input(&input);
+    let count = |mode| Answer::from(day_02::count_valid_passwords(&passwords, mode));
+    assert_eq!(
+        (
+            count(day_02::PolicyMode::CountRange),
+            count(day_02::PolicyMode::Positions)
+        ),
+        expected(2)
+    );
+}
+
+#[test]
+fn day_03() {
+    let mut map = day_03::TobogganMap::from_string_map(&day_03::get_input_test());
+    let trees = day_03::count_trees(&mut map, 3, 1);
+
+    let mut product = 1;
+    for (dx, dy) in [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter() {
+        map.reset_position();
+        product *= day_03::count_trees(&mut map, *dx, *dy);
+    }
+    assert_eq!((Answer::from(trees), Answer::from(product)), expected(3));
+}
+
+#[test]
+fn day_04() {
+    let input = day_04::get_input_test();
+    let present = day_04::count_present_passports(&input);
+    let valid = day_04::parse_string_to_passports(&input).len();
+    assert_eq!((Answer::from(present), Answer::from(valid)), expected(4));
+}
+
+#[test]
+fn day_05() {
+    let ids = day_05::seat_ids(&day_05::get_input_test());
+    assert_eq!(ids.last().map(|id| Answer::from(*id)), Some(expected(5).0));
+}
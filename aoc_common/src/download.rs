@@ -0,0 +1,623 @@
+/*!
+Downloading input data and submitting answers. Only built with the
+"download" feature, which is on by default.
+!*/
+
+use crate::AOC_PREFETCH_DELAY;
+use crate::{answer_url, cache_dir, cache_path, input_url, retry, AocError, Options};
+use crate::{AOC_RETRY_ATTEMPTS, AOC_RETRY_DELAY, AOC_TIMEOUT, AOC_YEAR};
+use anyhow::{bail, Result};
+use log::{info, warn};
+use std::env;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::{self, IsTerminal, Read, Write};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::Duration;
+
+static AOC_SESSION_ENV: &str = "AOC_SESSION";
+static AOC_SESSION_FILE: &str = ".aoc-session";
+static AOC_PROXY_ENV: [&str; 2] = ["HTTPS_PROXY", "HTTP_PROXY"];
+
+/// Download input data for given day based on the options. The cache is
+/// used unless `--refresh` is given.
+pub(crate) fn download_input(day: u32, opts: &Options) -> Result<String> {
+    // Progress is only shown to a person watching the terminal.
+    let progress = !opts.json && io::stderr().is_terminal();
+    let (data, origin) = fetch(opts.year, day, opts.refresh, progress)?;
+    match origin {
+        Origin::Cache => info!(
+            "Using cached test data from: {}",
+            cache_path(opts.year, day).display()
+        ),
+        Origin::Network => info!("Downloaded test data from: {}", input_url(opts.year, day)),
+    }
+    Ok(data)
+}
+
+/// Get the session key. "AOC_SESSION" environment variable takes
+/// precedence over the ".aoc-session" files.
+pub fn session_key() -> Result<String> {
+    match session_keys().into_iter().next() {
+        Some((_, key)) => Ok(key),
+        None => bail!(AocError::SessionMissing),
+    }
+}
+
+/// Get every available session key and where it was read from.
+fn session_keys() -> Vec<(String, String)> {
+    read_session_keys(env::var(AOC_SESSION_ENV).ok(), &session_files())
+}
+
+/// Session files in the order they are tried: current folder first,
+/// then home folder.
+fn session_files() -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(AOC_SESSION_FILE)];
+    if let Some(home) = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")) {
+        files.push(Path::new(&home).join(AOC_SESSION_FILE));
+    }
+    files
+}
+
+fn read_session_keys(env_value: Option<String>, files: &[PathBuf]) -> Vec<(String, String)> {
+    let mut keys: Vec<(String, String)> = Vec::new();
+    if let Some(key) = env_value.filter(|k| !k.is_empty()) {
+        keys.push((AOC_SESSION_ENV.to_string(), key));
+    }
+
+    for f in files {
+        let key = match read_to_string(f) {
+            Ok(key) => key.trim().to_string(),
+            Err(_) => continue,
+        };
+        // Same file may be listed twice, for example when run in home folder.
+        if !key.is_empty() && !keys.iter().any(|(_, k)| *k == key) {
+            keys.push((f.display().to_string(), key));
+        }
+    }
+    keys
+}
+
+/// Build the HTTP client used for downloads. A hung connection fails
+/// after `AOC_TIMEOUT` instead of blocking forever. Proxy is taken from
+/// "HTTPS_PROXY" or "HTTP_PROXY" environment variable if set. Gzip
+/// compressed responses are decompressed transparently.
+pub fn http_client() -> Result<reqwest::blocking::Client> {
+    let builder = reqwest::blocking::Client::builder()
+        .connect_timeout(AOC_TIMEOUT)
+        .timeout(AOC_TIMEOUT)
+        .gzip(true);
+    Ok(with_proxy(builder, proxy_url()).build()?)
+}
+
+/// Proxy address from the environment. "HTTPS_PROXY" takes precedence
+/// because the input data is downloaded over HTTPS.
+fn proxy_url() -> Option<String> {
+    AOC_PROXY_ENV
+        .iter()
+        .filter_map(|k| env::var(k).ok())
+        .find(|url| !url.is_empty())
+}
+
+/// Route all requests through given proxy. An invalid proxy address is
+/// reported and a direct connection is used instead.
+fn with_proxy(
+    builder: reqwest::blocking::ClientBuilder,
+    url: Option<String>,
+) -> reqwest::blocking::ClientBuilder {
+    let url = match url {
+        Some(url) => url,
+        None => return builder,
+    };
+
+    match reqwest::Proxy::all(&url) {
+        Ok(proxy) => builder.proxy(proxy),
+        Err(e) => {
+            warn!("Ignoring proxy {:?}. {}", url, e);
+            builder.no_proxy()
+        }
+    }
+}
+
+/// Get input data for given year and day from the cache if it has been
+/// downloaded before. Otherwise download it and store it to the cache.
+pub fn fetch_input(year: u32, day: u32) -> Result<String> {
+    fetch(year, day, false, false).map(|(data, _)| data)
+}
+
+/// Where `fetch` got the input data from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Origin {
+    Cache,
+    Network,
+}
+
+/// Get input data from the cache, or download it if it is not cached or
+/// `refresh` is set. Downloaded data is stored to the cache. With
+/// `progress` the downloaded byte count is shown while downloading.
+fn fetch(year: u32, day: u32, refresh: bool, progress: bool) -> Result<(String, Origin)> {
+    if !refresh {
+        if let Some(data) = read_cache(year, day) {
+            return Ok((data, Origin::Cache));
+        }
+    }
+
+    // Download checks the response, so only valid input data is cached.
+    let client = http_client()?;
+    let data = download(&client, &input_url(year, day), &session_keys(), progress)?;
+    write_cache(year, day, &data);
+
+    Ok((data, Origin::Network))
+}
+
+/// Download and cache input data of the given days of `year`, for
+/// example all of them at the start of the event. Days that are already
+/// cached are skipped. Downloads share one client and are spaced by
+/// `AOC_PREFETCH_DELAY` to be polite to Advent of Code. Returns the
+/// result of each day in order.
+pub fn prefetch_all(year: u32, days: RangeInclusive<u32>) -> Vec<Result<()>> {
+    let client = http_client();
+    let session_keys = session_keys();
+    let mut first = true;
+
+    let prefetch_day = |day: u32| -> Result<()> {
+        if read_cache(year, day).is_some() {
+            return Ok(());
+        }
+        let client = match &client {
+            Ok(client) => client,
+            Err(e) => bail!("{}", e),
+        };
+        if !first {
+            sleep(AOC_PREFETCH_DELAY);
+        }
+        first = false;
+
+        let data = download(client, &input_url(year, day), &session_keys, false)?;
+        write_cache(year, day, &data);
+        Ok(())
+    };
+    days.map(prefetch_day).collect()
+}
+
+/// Read cached input data for given year and day. Cache written by an
+/// older version may hold an error page, which is ignored.
+fn read_cache(year: u32, day: u32) -> Option<String> {
+    read_to_string(cache_path(year, day))
+        .ok()
+        .and_then(|data| check_response(data).ok())
+}
+
+/// Store input data for given year and day to the cache.
+fn write_cache(year: u32, day: u32, data: &str) {
+    // Failing to write the cache is not fatal, the data is still valid.
+    let _ = create_dir_all(cache_dir()).and_then(|_| write(cache_path(year, day), data));
+}
+
+/// This function downloads input data for given year and day from Advent
+/// of Code if session key is available and download succeeds. The cache
+/// is not used.
+pub fn fetch_input_uncached(year: u32, day: u32) -> Result<String> {
+    download(
+        &http_client()?,
+        &input_url(year, day),
+        &session_keys(),
+        false,
+    )
+}
+
+/// Download `url` trying each session key in turn until one is not
+/// rejected. Errors other than a rejected key are returned right away.
+fn download(
+    client: &reqwest::blocking::Client,
+    url: &str,
+    session_keys: &[(String, String)],
+    progress: bool,
+) -> Result<String> {
+    let mut error = anyhow::Error::new(AocError::SessionMissing);
+
+    for (source, session_key) in session_keys {
+        let response = retry(AOC_RETRY_ATTEMPTS, AOC_RETRY_DELAY, || {
+            Ok(client
+                .get(url)
+                .header("Cookie", format!("session={}", session_key))
+                .send()?)
+        })?;
+
+        match check_status(response.status()) {
+            Ok(()) => {
+                info!("Using session key from: {}", source);
+                let body = if progress {
+                    read_with_progress(response, io::stderr())?
+                } else {
+                    response.bytes()?.to_vec()
+                };
+                return check_response(decode_body(body));
+            }
+            Err(e) => match e.downcast_ref::<AocError>() {
+                Some(AocError::SessionRejected(_)) => {
+                    warn!("Session key from {} was rejected.", source);
+                    error = e;
+                }
+                _ => return Err(e),
+            },
+        }
+    }
+
+    Err(error)
+}
+
+/// Check that a session key works by downloading day 1 input data.
+/// Tells apart a missing key, a key rejected by Advent of Code and
+/// other failures.
+pub fn check_session() -> Result<()> {
+    download(
+        &http_client()?,
+        &input_url(AOC_YEAR, 1),
+        &session_keys(),
+        false,
+    )
+    .map(|_| ())
+}
+
+/// Read the whole body while writing the byte count so far to `out` on
+/// one line. The line is cleared when the body has been read.
+fn read_with_progress<R: Read, W: Write>(mut body: R, mut out: W) -> io::Result<Vec<u8>> {
+    let mut data = Vec::new();
+    let mut chunk = [0; 8192];
+    loop {
+        let len = body.read(&mut chunk)?;
+        if len == 0 {
+            break;
+        }
+        data.extend_from_slice(&chunk[..len]);
+        write!(out, "\rDownloading... {} bytes", data.len())?;
+        out.flush()?;
+    }
+    write!(out, "\r\x1b[K")?;
+    Ok(data)
+}
+
+/// Decode response body as UTF-8. Input data is plain ASCII, but bytes
+/// mangled on the way, for example by a proxy, are replaced instead of
+/// failing the whole download.
+fn decode_body(data: Vec<u8>) -> String {
+    match String::from_utf8(data) {
+        Ok(text) => text,
+        Err(e) => {
+            warn!("Downloaded data is not valid UTF-8. Invalid bytes are replaced.");
+            String::from_utf8_lossy(e.as_bytes()).into_owned()
+        }
+    }
+}
+
+/// Turn unsuccessful response status into an error. Advent of Code
+/// answers 400 or 401 when the session key is not valid.
+fn check_status(status: reqwest::StatusCode) -> Result<()> {
+    match status {
+        s if s.is_success() => Ok(()),
+        reqwest::StatusCode::BAD_REQUEST | reqwest::StatusCode::UNAUTHORIZED => {
+            bail!(AocError::SessionRejected(status))
+        }
+        _ => bail!(AocError::HttpFailed(status)),
+    }
+}
+
+/// Advent of Code answers some requests, for example too early or too
+/// frequent ones, with an HTML page instead of the input data. Such
+/// responses are rejected so they are not parsed or cached as input.
+/// Empty or whitespace only bodies are rejected as well, they would give
+/// a wrong answer instead of falling back to the test data.
+pub fn check_response(body: String) -> Result<String> {
+    if body.trim().is_empty() {
+        bail!(AocError::EmptyInput);
+    }
+    let is_html = body.trim_start().starts_with("<!DOCTYPE")
+        || body.contains("Please don't repeatedly request");
+    if is_html {
+        bail!(AocError::NotInputData);
+    }
+    Ok(body)
+}
+
+/// How Advent of Code judged a submitted answer.
+#[derive(Debug, PartialEq)]
+pub enum SubmitResult {
+    /// The answer is right.
+    Correct,
+    /// The answer is wrong.
+    Incorrect,
+    /// This part has already been solved, the answer was not checked.
+    AlreadyDone,
+    /// An answer was submitted too recently. Holds the time left to wait
+    /// when Advent of Code tells it.
+    TooSoon(Option<Duration>),
+}
+
+/// Submit an answer for given part (`level` 1 or 2) of given day and
+/// year, like `opts.year`, and report whether it was right. The request is sent only once, never
+/// retried, since every submission counts towards the rate limit and a
+/// wrong answer makes Advent of Code wait longer before the next one.
+pub fn submit_answer(year: u32, day: u32, level: u32, answer: &str) -> Result<SubmitResult> {
+    let session_key = session_key()?;
+    let response = http_client()?
+        .post(&answer_url(year, day))
+        .header("Cookie", format!("session={}", session_key))
+        .form(&[("level", level.to_string()), ("answer", answer.to_string())])
+        .send()?;
+
+    check_status(response.status())?;
+    let result = parse_submit_response(&response.text()?)?;
+    if let SubmitResult::TooSoon(wait) = &result {
+        warn!(
+            "Answer was submitted too recently. Wait {} before the next one.",
+            match wait {
+                Some(wait) => format!("{} s", wait.as_secs()),
+                None => "a while".to_string(),
+            }
+        );
+    }
+    Ok(result)
+}
+
+/// Find out the result from the web page Advent of Code answers a
+/// submission with.
+fn parse_submit_response(body: &str) -> Result<SubmitResult> {
+    if body.contains("That's the right answer") {
+        Ok(SubmitResult::Correct)
+    } else if body.contains("That's not the right answer") {
+        Ok(SubmitResult::Incorrect)
+    } else if body.contains("You don't seem to be solving the right level") {
+        Ok(SubmitResult::AlreadyDone)
+    } else if body.contains("You gave an answer too recently") {
+        Ok(SubmitResult::TooSoon(parse_wait_time(body)))
+    } else {
+        bail!("Unexpected response to the submitted answer.")
+    }
+}
+
+/// Parse the wait time from text like "You have 1m 5s left to wait."
+fn parse_wait_time(body: &str) -> Option<Duration> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for part in body[start..end].split_whitespace() {
+        let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        secs += match unit {
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use super::common::{serve, Reply};
+    use super::*;
+
+    #[test]
+    fn missing_session_file() {
+        let keys = read_session_keys(None, &[PathBuf::from(AOC_SESSION_FILE)]);
+        assert!(keys.is_empty());
+        let e = download(&http_client().unwrap(), "http://127.0.0.1:9/", &keys, false).unwrap_err();
+        assert_eq!(
+            e.downcast_ref::<AocError>(),
+            Some(&AocError::SessionMissing)
+        );
+    }
+
+    #[test]
+    fn second_session_file_is_used() {
+        let dir = env::temp_dir();
+        let files = [
+            dir.join("aoc_common_session_1"),
+            dir.join("aoc_common_session_2"),
+        ];
+        write(&files[0], "expired\n").unwrap();
+        write(&files[1], "valid\n").unwrap();
+        let keys = read_session_keys(None, &files);
+        for f in files.iter() {
+            std::fs::remove_file(f).unwrap();
+        }
+        assert_eq!(keys.len(), 2);
+
+        // Accepts only the "valid" session key.
+        let (address, server) = serve(2, |request| {
+            if request.contains("session=valid") {
+                Reply::ok("1721\n")
+            } else {
+                Reply::status("400 Bad Request")
+            }
+        });
+        let url = format!("{}/", address);
+
+        let data = download(&http_client().unwrap(), &url, &keys, true);
+        server.join().unwrap();
+        assert_eq!(data.unwrap(), "1721\n");
+    }
+
+    #[test]
+    fn session_from_env() {
+        env::set_var(AOC_SESSION_ENV, "abc123");
+        let key = session_key();
+        env::remove_var(AOC_SESSION_ENV);
+        assert_eq!(key.unwrap(), "abc123");
+    }
+
+    #[test]
+    fn response_status() {
+        use reqwest::StatusCode;
+
+        assert!(check_status(StatusCode::OK).is_ok());
+        for status in &[StatusCode::BAD_REQUEST, StatusCode::UNAUTHORIZED] {
+            let e = check_status(*status).unwrap_err();
+            assert_eq!(
+                e.downcast_ref::<AocError>(),
+                Some(&AocError::SessionRejected(*status))
+            );
+        }
+        let e = check_status(StatusCode::NOT_FOUND).unwrap_err();
+        assert_eq!(
+            e.downcast_ref::<AocError>(),
+            Some(&AocError::HttpFailed(StatusCode::NOT_FOUND))
+        );
+        let e = AocError::HttpFailed(StatusCode::BAD_REQUEST);
+        assert_eq!(e.to_string(), "Download failed. Response: 400 Bad Request");
+    }
+
+    #[test]
+    fn html_response_is_rejected() {
+        let page = "<!DOCTYPE html>\n<html lang=\"en-us\">\n</html>";
+        let e = check_response(page.to_string()).unwrap_err();
+        assert_eq!(e.downcast_ref::<AocError>(), Some(&AocError::NotInputData));
+
+        let text = "Please don't repeatedly request this endpoint before it unlocks!";
+        assert!(check_response(text.to_string()).is_err());
+
+        assert_eq!(
+            check_response("1721\n979\n".to_string()).unwrap(),
+            "1721\n979\n"
+        );
+    }
+
+    #[test]
+    fn empty_response_is_rejected() {
+        assert!(check_response(" \r\n".to_string()).is_err());
+
+        let (address, server) = serve(1, |_| Reply::ok(""));
+        let url = format!("{}/", address);
+
+        let keys = vec![("test".to_string(), "abc123".to_string())];
+        let e = download(&http_client().unwrap(), &url, &keys, false).unwrap_err();
+        server.join().unwrap();
+        assert_eq!(e.downcast_ref::<AocError>(), Some(&AocError::EmptyInput));
+        assert_eq!(e.to_string(), "Download failed. Empty input body.");
+    }
+
+    #[test]
+    fn client_builds() {
+        assert!(http_client().is_ok());
+    }
+
+    #[test]
+    fn gzip_response_is_decoded() {
+        // "1721\n979\n366\n" compressed with gzip.
+        let body: &[u8] = &[
+            31, 139, 8, 0, 0, 0, 0, 0, 2, 255, 51, 52, 55, 50, 228, 178, 52, 183, 228, 50, 54, 51,
+            227, 2, 0, 174, 23, 1, 226, 13, 0, 0, 0,
+        ];
+        let (address, server) = serve(1, move |_| Reply::ok(body).header("Content-Encoding: gzip"));
+        let url = format!("{}/", address);
+
+        let response = http_client().unwrap().get(&url).send().unwrap();
+        let text = response.text().unwrap();
+        server.join().unwrap();
+        assert_eq!(text, "1721\n979\n366\n");
+    }
+
+    #[test]
+    fn invalid_utf8_is_replaced() {
+        let body: &[u8] = b"1721\n\xff979\n366\n";
+        let (address, server) = serve(1, move |_| Reply::ok(body));
+        let url = format!("{}/", address);
+
+        let keys = [("test".to_string(), "abc123".to_string())];
+        let text = download(&http_client().unwrap(), &url, &keys, false).unwrap();
+        server.join().unwrap();
+        assert_eq!(text, "1721\n\u{fffd}979\n366\n");
+        assert_eq!(decode_body(b"1721\n".to_vec()), "1721\n");
+    }
+
+    #[test]
+    fn progress_is_cleared() {
+        let body = vec![b'1'; 10000];
+        let mut out = Vec::new();
+        let data = read_with_progress(&body[..], &mut out).unwrap();
+        assert_eq!(data, body);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("\rDownloading... 8192 bytes"));
+        assert!(out.contains("\rDownloading... 10000 bytes"));
+        assert!(out.ends_with("\r\x1b[K"));
+    }
+
+    #[test]
+    fn submit_responses() {
+        let page = |text: &str| {
+            format!(
+                "<!DOCTYPE html><main><article><p>{}</p></article></main>",
+                text
+            )
+        };
+        assert_eq!(
+            parse_submit_response(&page(
+                "That's the right answer! You are one gold star closer."
+            ))
+            .unwrap(),
+            SubmitResult::Correct
+        );
+        assert_eq!(
+            parse_submit_response(&page(
+                "That's not the right answer; your answer is too low."
+            ))
+            .unwrap(),
+            SubmitResult::Incorrect
+        );
+        assert_eq!(
+            parse_submit_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+            .unwrap(),
+            SubmitResult::AlreadyDone
+        );
+        assert_eq!(
+            parse_submit_response(&page(
+                "You gave an answer too recently. You have 1m 5s left to wait."
+            ))
+            .unwrap(),
+            SubmitResult::TooSoon(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            parse_submit_response(&page("You gave an answer too recently.")).unwrap(),
+            SubmitResult::TooSoon(None)
+        );
+        assert!(parse_submit_response(&page("Something else")).is_err());
+    }
+
+    #[test]
+    fn client_with_proxy() {
+        let builder = reqwest::blocking::Client::builder();
+        let url = Some("http://localhost:3128".to_string());
+        assert!(with_proxy(builder, url).build().is_ok());
+    }
+
+    #[test]
+    fn invalid_proxy_is_ignored() {
+        let builder = reqwest::blocking::Client::builder();
+        let url = Some("http://[not-a-proxy".to_string());
+        assert!(with_proxy(builder, url).build().is_ok());
+    }
+
+    #[test]
+    fn cached_input_is_used() {
+        // Other tests don't use the cache, so changing it here is safe.
+        let dir = env::temp_dir().join("aoc_common_cache");
+        env::set_var(crate::AOC_CACHE_ENV, &dir);
+        create_dir_all(&dir).unwrap();
+        write(cache_path(AOC_YEAR, 90), "cached").unwrap();
+        let data = fetch_input(AOC_YEAR, 90);
+        let fetched = fetch(AOC_YEAR, 90, false, false);
+        std::fs::remove_dir_all(&dir).unwrap();
+        env::remove_var(crate::AOC_CACHE_ENV);
+        assert_eq!(data.unwrap(), "cached");
+        assert_eq!(fetched.unwrap(), ("cached".to_string(), Origin::Cache));
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// Errors that callers may want to handle separately instead of just
+/// printing them.
+#[derive(Debug, PartialEq)]
+pub enum AocError {
+    /// Neither "AOC_SESSION" nor ".aoc-session" is available.
+    SessionMissing,
+    /// Advent of Code did not accept the session key, it has probably
+    /// expired.
+    #[cfg(feature = "download")]
+    SessionRejected(reqwest::StatusCode),
+    /// Advent of Code responded with an unsuccessful status.
+    #[cfg(feature = "download")]
+    HttpFailed(reqwest::StatusCode),
+    /// Advent of Code responded with a web page instead of input data.
+    NotInputData,
+    /// Advent of Code responded with an empty body, for example before
+    /// the puzzle is unlocked.
+    EmptyInput,
+    /// Input data could not be parsed.
+    ParseFailed(String),
+    /// Input data was valid but has no answer.
+    NoSolution,
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::SessionMissing => {
+                write!(
+                    f,
+                    "Session key not found. Set AOC_SESSION or create \".aoc-session\"."
+                )
+            }
+            #[cfg(feature = "download")]
+            AocError::SessionRejected(status) => {
+                write!(f, "Session key was rejected. Response: {}", status)
+            }
+            #[cfg(feature = "download")]
+            AocError::HttpFailed(status) => write!(f, "Download failed. Response: {}", status),
+            AocError::NotInputData => {
+                write!(
+                    f,
+                    "Download failed. Response is a web page, not input data."
+                )
+            }
+            AocError::EmptyInput => write!(f, "Download failed. Empty input body."),
+            AocError::ParseFailed(reason) => write!(f, "Failed to parse input data. {}", reason),
+            AocError::NoSolution => write!(f, "No solution found."),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
@@ -0,0 +1,121 @@
+/*!
+Two-dimensional grid of cells for map-like puzzle inputs.
+!*/
+
+/// Grid stored row by row, x is the column and y the row counting from
+/// the top-left corner. Rows may be of different lengths, width is the
+/// length of the longest row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    rows: Vec<Vec<T>>,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    /// Create grid from the lines of `input`, converting each character
+    /// with `cell`. Lines are trimmed, so indented input works too.
+    pub fn from_lines<F: FnMut(char) -> T>(input: &str, mut cell: F) -> Grid<T> {
+        let rows: Vec<Vec<T>> = input
+            .lines()
+            .map(|line| line.trim().chars().map(&mut cell).collect())
+            .collect();
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        Grid { rows, width }
+    }
+
+    /// Length of the longest row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Cell at (x, y), or None if it is outside the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.rows.get(y)?.get(x)
+    }
+
+    /// Cell at (x, y) where the grid repeats infinitely on x-axis, in
+    /// both directions. Returns None if y is outside the grid.
+    pub fn get_wrapping_x(&self, x: isize, y: usize) -> Option<&T> {
+        if self.width == 0 {
+            return None;
+        }
+        self.get(x.rem_euclid(self.width as isize) as usize, y)
+    }
+
+    /// Rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.rows.iter().map(|row| row.as_slice())
+    }
+
+    /// Every cell with its position as (x, y, cell), row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| (x, y, cell)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::from_lines(
+            "..#
+            #..
+            .#",
+            |c| c,
+        )
+    }
+
+    #[test]
+    fn parse_lines() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["..#", "#..", ".#"]);
+
+        let trees = Grid::from_lines("#.\n.#", |c| c == '#');
+        assert_eq!(trees.get(1, 1), Some(&true));
+        let empty: Grid<char> = Grid::from_lines("", |c| c);
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+    }
+
+    #[test]
+    fn bounds() {
+        let grid = sample();
+        assert_eq!(grid.get(2, 0), Some(&'#'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        // Last row is shorter than the grid width.
+        assert_eq!(grid.get(2, 2), None);
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = sample();
+        assert_eq!(grid.get_wrapping_x(3, 1), Some(&'#'));
+        assert_eq!(grid.get_wrapping_x(-1, 0), Some(&'#'));
+        assert_eq!(grid.get_wrapping_x(-4, 0), Some(&'#'));
+        assert_eq!(grid.get_wrapping_x(7, 2), Some(&'#'));
+        assert_eq!(grid.get_wrapping_x(0, 3), None);
+        let empty: Grid<char> = Grid::from_lines("", |c| c);
+        assert_eq!(empty.get_wrapping_x(1, 0), None);
+    }
+
+    #[test]
+    fn cells_in_order() {
+        let trees: Vec<(usize, usize)> = sample()
+            .cells()
+            .filter(|(_, _, c)| **c == '#')
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        assert_eq!(trees, vec![(2, 0), (0, 1), (1, 2)]);
+    }
+}
@@ -0,0 +1,664 @@
+/*!
+# Advent of Code 2020 - Common
+
+Shared helpers used by every day's solution.
+
+Input data is downloaded from Advent of Code website using the session
+cookie. The cookie is read from "AOC_SESSION" environment variable and
+from ".aoc-session" files in current folder and in home folder. They are
+tried in that order until Advent of Code accepts one. Each day supplies
+its own hard-coded test data as a fall-back when download is not possible.
+
+Downloaded input is cached to ".aoc-cache/YYYY_day_NN.txt" so that repeated
+runs don't hit the Advent of Code servers again. "AOC_CACHE" environment
+variable changes the cache folder.
+
+Set "AOC_BASE_URL" environment variable to download from another address
+than "https://adventofcode.com", for example from a local test server.
+
+Downloading is behind the "download" feature, which is on by default.
+Without it reqwest is not built, and only the hard-coded test data and
+`--input` files are used.
+
+Answers can be checked with `submit_answer`, which sends them to Advent
+of Code once and reports whether they were right.
+
+`Grid` holds map-like input data, with the map optionally repeating
+sideways.
+
+Downloads go through the proxy given in "HTTPS_PROXY" or "HTTP_PROXY"
+environment variable when one is set.
+
+## Command line options
+
+```text ignore
+--offline         Use the hard-coded test data. Can also be set with
+                  "AOC_OFFLINE" environment variable.
+--input <path>    Read input data from a local file. Can also be set
+                  with "AOC_INPUT" environment variable.
+--no-fallback     Fail instead of using the hard-coded test data when
+                  input data is not available. Can also be set with
+                  "AOC_NO_FALLBACK" environment variable.
+--refresh         Download input data again even if it is cached.
+--debug           Print extra diagnostics about the input data.
+--verbose         Print details behind the answer, like the passports
+                  counted as valid.
+--year <year>     Advent of Code year to download input data for. Can
+                  also be set with "AOC_YEAR" environment variable.
+                  Defaults to 2020.
+--time            Print how long solving took. Can also be set with
+                  "AOC_TIME" environment variable.
+--day <day>       Day to solve with aoc_runner.
+--part <part>     Print only the answer of part 1 or 2. Both are
+                  printed by default.
+--json            Print the answer as a JSON line, like
+                  `{"day":1,"answer":241861950}`, instead of text. Can
+                  also be set with "AOC_FORMAT=json" environment variable.
+```
+
+Diagnostic messages are logged to stderr. Set "RUST_LOG" environment
+variable to `warn` to hide the informational messages.
+!*/
+
+mod answer;
+#[cfg(feature = "download")]
+mod download;
+mod error;
+mod grid;
+mod solver;
+
+pub use answer::Answer;
+
+#[cfg(feature = "download")]
+use download::download_input;
+#[cfg(feature = "download")]
+pub use download::{
+    check_response, check_session, fetch_input, fetch_input_uncached, http_client, prefetch_all,
+    session_key, submit_answer, SubmitResult,
+};
+pub use error::AocError;
+pub use grid::Grid;
+pub use solver::Solver;
+
+use anyhow::{bail, Result};
+use clap::builder::{BoolishValueParser, PossibleValuesParser, TypedValueParser};
+use clap::Parser;
+use log::{info, warn, Level};
+use serde::Serialize;
+use std::env;
+use std::fmt::Display;
+use std::fs::{read_to_string, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+static AOC_OFFLINE_ENV: &str = "AOC_OFFLINE";
+static AOC_CACHE_ENV: &str = "AOC_CACHE";
+static AOC_CACHE_DIR: &str = ".aoc-cache";
+static AOC_BASE_URL_ENV: &str = "AOC_BASE_URL";
+static AOC_BASE_URL: &str = "https://adventofcode.com";
+static AOC_RESULTS_ENV: &str = "AOC_RESULTS";
+static AOC_RESULTS_FILE: &str = "results.txt";
+
+/// Connect and read timeout for the download.
+pub const AOC_TIMEOUT: Duration = Duration::from_secs(10);
+/// How many times the download is attempted before giving up.
+pub const AOC_RETRY_ATTEMPTS: u32 = 3;
+/// Delay before the first retry. Doubled after every failed attempt.
+pub const AOC_RETRY_DELAY: Duration = Duration::from_millis(500);
+/// Delay between downloads when prefetching several days.
+pub const AOC_PREFETCH_DELAY: Duration = Duration::from_secs(1);
+/// Advent of Code year used unless another one is given.
+pub const AOC_YEAR: u32 = 2020;
+
+/// Command line options shared by every day.
+#[derive(Parser, Debug)]
+pub struct Options {
+    /// Use the hard-coded test data instead of downloading input data.
+    #[arg(long, env = AOC_OFFLINE_ENV, value_parser = BoolishValueParser::new())]
+    pub offline: bool,
+    /// Read input data from a local file.
+    #[arg(long, env = "AOC_INPUT")]
+    pub input: Option<PathBuf>,
+    /// Fail instead of using the hard-coded test data when input data is
+    /// not available. `--offline` still uses the test data.
+    #[arg(long, env = "AOC_NO_FALLBACK", value_parser = BoolishValueParser::new())]
+    pub no_fallback: bool,
+    /// Download input data again even if it is cached.
+    #[arg(long)]
+    pub refresh: bool,
+    /// Print extra diagnostics about the input data.
+    #[arg(long)]
+    pub debug: bool,
+    /// Print details behind the answer.
+    #[arg(long)]
+    pub verbose: bool,
+    /// Advent of Code year to download input data for.
+    #[arg(long, env = "AOC_YEAR", default_value_t = AOC_YEAR)]
+    pub year: u32,
+    /// Print how long solving took.
+    #[arg(long, env = "AOC_TIME", value_parser = BoolishValueParser::new())]
+    pub time: bool,
+    /// Day to solve. Only used by aoc_runner.
+    #[arg(long)]
+    pub day: Option<u32>,
+    /// Print only the answer of given part, 1 or 2.
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    pub part: Option<u32>,
+    /// Print the answer as JSON instead of text.
+    #[arg(
+        long,
+        env = "AOC_FORMAT",
+        value_parser = PossibleValuesParser::new(["json", "text", "true", "false"])
+            .map(|s| s == "json" || s == "true")
+    )]
+    pub json: bool,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            offline: false,
+            input: None,
+            no_fallback: false,
+            refresh: false,
+            debug: false,
+            verbose: false,
+            year: AOC_YEAR,
+            time: false,
+            day: None,
+            part: None,
+            json: false,
+        }
+    }
+}
+
+impl Options {
+    /// Parse options from command line. Invalid arguments print the error
+    /// with usage and exit, so a typo'd option is never silently ignored.
+    pub fn from_args() -> Options {
+        Options::parse()
+    }
+}
+
+impl Options {
+    /// Whether the answer of given part is printed. Both parts are unless
+    /// one is selected with `--part`.
+    pub fn show_part(&self, part: u32) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+/// Where input data given by `input_or_fallback` came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputSource {
+    /// Puzzle input from a local file, the cache or a download.
+    Puzzle,
+    /// Hard-coded test data of the day.
+    Fallback,
+}
+
+/// Append answer of given day and part to the results file, so there
+/// is a log of solved answers over the event. The file is "results.txt"
+/// in current folder unless "AOC_RESULTS" gives another path. Answers
+/// solved from the hard-coded test data are not recorded. Failing to
+/// write is only logged, the answer has already been printed.
+pub fn record_answer(source: InputSource, day: u32, part: u32, answer: &str) {
+    if source == InputSource::Fallback {
+        return;
+    }
+    let path = match env::var(AOC_RESULTS_ENV) {
+        Ok(path) if !path.is_empty() => PathBuf::from(path),
+        _ => PathBuf::from(AOC_RESULTS_FILE),
+    };
+    if let Err(e) = append_answer(&path, day, part, answer) {
+        warn!("Failed to record answer to {:?}. {}", path, e);
+    }
+}
+
+/// Append one line with the current time as seconds since Unix epoch,
+/// the day, the part and the answer.
+fn append_answer(path: &Path, day: u32, part: u32, answer: &str) -> io::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(
+        file,
+        "{} day {:02} part {}: {}",
+        timestamp, day, part, answer
+    )
+}
+
+/// Print time elapsed since `start` if `--time` option is given. Start
+/// the timer after input data is loaded, so download is not included.
+pub fn print_solve_time(opts: &Options, start: Instant) {
+    if opts.time {
+        println!(
+            "Solved in {:.3} ms.",
+            start.elapsed().as_secs_f64() * 1000.0
+        );
+    }
+}
+
+/// Format the answer of given day as a single line of JSON, for example
+/// `{"day":1,"answer":241861950}`. The answer can be any serializable
+/// value, since its type differs between days.
+pub fn answer_json<T: Serialize>(day: u32, answer: T) -> String {
+    serde_json::json!({ "day": day, "answer": answer }).to_string()
+}
+
+/// Set up logging of diagnostic messages to stderr. Messages are shown
+/// as "Info: ..." and "Warning: ...", info level by default. Use
+/// "RUST_LOG" environment variable to change the level, for example
+/// `RUST_LOG=warn` hides the info messages.
+pub fn init_logger() {
+    let env = env_logger::Env::default().default_filter_or("info");
+    // Logger may already be set, for example when main is run by tests.
+    let _ = env_logger::Builder::from_env(env)
+        .format(|buf, record| {
+            let label = match record.level() {
+                Level::Error => "Error",
+                Level::Warn => "Warning",
+                Level::Info => "Info",
+                Level::Debug => "Debug",
+                Level::Trace => "Trace",
+            };
+            writeln!(buf, "{}: {}", label, record.args())
+        })
+        .try_init();
+}
+
+/// Get input data for given day based on the options. Local input file
+/// is preferred, then the cache and finally download.
+pub fn load_input(day: u32, opts: &Options) -> Result<String> {
+    if let Some(path) = &opts.input {
+        info!("Reading input data from: {}", path.display());
+        return get_input_from_file(path);
+    }
+
+    if opts.offline {
+        bail!("Offline mode.");
+    }
+
+    download_input(day, opts)
+}
+
+/// Input data can't be downloaded without the "download" feature.
+#[cfg(not(feature = "download"))]
+fn download_input(_day: u32, _opts: &Options) -> Result<String> {
+    bail!("Built without download support.")
+}
+
+/// Get input data for given day using the options, and where it came
+/// from. If it is not available, the reason is logged and `fallback` is
+/// used instead, or with `--no-fallback` the reason is returned as an
+/// error. Data from any source is normalized with `normalize_input`.
+/// With `--debug` the input summary is printed.
+pub fn input_or_fallback(
+    day: u32,
+    opts: &Options,
+    fallback: fn() -> String,
+) -> Result<(String, InputSource)> {
+    let (data, source) = match load_input(day, opts) {
+        Ok(data) => (normalize_input(&data), InputSource::Puzzle),
+        Err(e) if opts.no_fallback && !opts.offline => {
+            bail!("Input data not available and fallback is disabled. {}", e)
+        }
+        Err(e) => {
+            info!("Using hard-coded test data. {}", e);
+            (normalize_input(&fallback()), InputSource::Fallback)
+        }
+    };
+
+    if opts.debug {
+        let (lines, checksum) = input_summary(&data);
+        println!(
+            "Debug: Input has {} lines, checksum {:016x}.",
+            lines, checksum
+        );
+    }
+    Ok((data, source))
+}
+
+/// Remove leading byte order mark, which browsers may add when saving
+/// the input, convert Windows line endings to "\n" and trim trailing
+/// whitespace at the end of the data.
+pub fn normalize_input(input: &str) -> String {
+    input
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .trim_end()
+        .to_string()
+}
+
+/// Count non-empty lines of input and calculate a checksum of them, so
+/// two inputs can be compared. Lines are trimmed before hashing, so line
+/// endings and indentation don't change the checksum. The checksum is
+/// 64-bit FNV-1a, which is stable across runs and platforms.
+pub fn input_summary(input: &str) -> (usize, u64) {
+    let mut lines = 0;
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        lines += 1;
+        for byte in line.bytes().chain(std::iter::once(b'\n')) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    (lines, hash)
+}
+
+/// Parse a number and check that it is between `low` and `high`,
+/// inclusive. Error tells whether the value was not a number or which
+/// bound it broke.
+pub fn parse_in_range<T: FromStr + PartialOrd + Display>(s: &str, low: T, high: T) -> Result<T> {
+    let value = match s.parse::<T>() {
+        Ok(value) => value,
+        Err(_) => bail!("\"{}\" is not a number", s),
+    };
+    if value < low {
+        bail!("{} is below {}", value, low);
+    }
+    if value > high {
+        bail!("{} is above {}", value, high);
+    }
+    Ok(value)
+}
+
+/// Split input into blocks separated by one or more blank lines. Works
+/// with both "\n" and "\r\n" line endings. Blocks are trimmed and
+/// empty blocks are not returned.
+pub fn split_blocks(input: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut end = 0;
+    let mut pos = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                blocks.push(input[start..end].trim());
+            }
+        } else {
+            start.get_or_insert(pos);
+            end = pos + line.len();
+        }
+        pos += line.len();
+    }
+    if let Some(start) = start {
+        blocks.push(input[start..end].trim());
+    }
+
+    blocks
+}
+
+/// Read input data from a local file.
+pub fn get_input_from_file(path: &Path) -> Result<String> {
+    match read_to_string(path) {
+        Ok(data) => Ok(data),
+        Err(e) => bail!("Failed to read {:?}. {}", path, e),
+    }
+}
+
+/// Build the input data URL for given year and day.
+pub fn input_url(year: u32, day: u32) -> String {
+    format!("{}/{}/day/{}/input", base_url(), year, day)
+}
+
+/// Build the answer submission URL for given year and day.
+pub fn answer_url(year: u32, day: u32) -> String {
+    format!("{}/{}/day/{}/answer", base_url(), year, day)
+}
+
+/// Address of Advent of Code website. Can be changed with "AOC_BASE_URL"
+/// environment variable, for example to test against a local server.
+pub fn base_url() -> String {
+    match env::var(AOC_BASE_URL_ENV) {
+        Ok(url) if !url.is_empty() => url.trim_end_matches('/').to_string(),
+        _ => AOC_BASE_URL.to_string(),
+    }
+}
+
+/// Cache folder, ".aoc-cache" in current folder unless "AOC_CACHE" gives
+/// another path.
+pub fn cache_dir() -> PathBuf {
+    match env::var(AOC_CACHE_ENV) {
+        Ok(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(AOC_CACHE_DIR),
+    }
+}
+
+/// Path of the cache file for given year and day.
+pub fn cache_path(year: u32, day: u32) -> PathBuf {
+    cache_file(&cache_dir(), year, day)
+}
+
+/// Path of the cache file for given year and day in `dir`.
+fn cache_file(dir: &Path, year: u32, day: u32) -> PathBuf {
+    dir.join(format!("{}_day_{:02}.txt", year, day))
+}
+
+/// Call `f` until it succeeds, at most `attempts` times. Waits `delay`
+/// before the first retry and doubles the wait after every failure.
+/// Returns the last error if all attempts fail.
+pub fn retry<T, F>(attempts: u32, delay: Duration, mut f: F) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+{
+    let mut delay = delay;
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(_) => {
+                sleep(delay);
+                delay *= 2;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod aoc_common {
+    use super::*;
+
+    #[test]
+    fn answer_is_appended() {
+        let path = env::temp_dir().join("aoc_common_results.txt");
+        let _ = std::fs::remove_file(&path);
+        append_answer(&path, 1, 1, "514579").unwrap();
+        append_answer(&path, 5, 2, "820").unwrap();
+        let data = read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let lines: Vec<&str> = data.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with(" day 01 part 1: 514579"));
+        assert!(lines[1].ends_with(" day 05 part 2: 820"));
+        assert!(lines[1].split(' ').next().unwrap().parse::<u64>().is_ok());
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_input("\u{feff}1721\r\n979\r\n\r\n"), "1721\n979");
+        assert_eq!(normalize_input("1721\n979"), "1721\n979");
+        assert_eq!(normalize_input(""), "");
+    }
+
+    #[test]
+    fn offline_option() {
+        let opts = Options::try_parse_from(["day_01", "--offline"]).unwrap();
+        let e = load_input(1, &opts).unwrap_err();
+        assert_eq!(e.to_string(), "Offline mode.");
+    }
+
+    #[test]
+    fn offline_from_env() {
+        env::set_var(AOC_OFFLINE_ENV, "1");
+        let opts = Options::try_parse_from(["day_01"]);
+        env::remove_var(AOC_OFFLINE_ENV);
+        assert!(opts.unwrap().offline);
+    }
+
+    #[test]
+    fn year_option() {
+        assert_eq!(Options::default().year, AOC_YEAR);
+        let opts = Options::try_parse_from(["day_01", "--year", "2021"]).unwrap();
+        assert_eq!(opts.year, 2021);
+    }
+
+    #[test]
+    fn json_option() {
+        assert!(!Options::default().json);
+        let opts = Options::try_parse_from(["day_01", "--json"]).unwrap();
+        assert!(opts.json);
+    }
+
+    #[test]
+    fn part_option() {
+        assert!(Options::default().show_part(1) && Options::default().show_part(2));
+        let opts = Options::try_parse_from(["day_01", "--part", "2"]).unwrap();
+        assert!(!opts.show_part(1) && opts.show_part(2));
+        assert!(Options::try_parse_from(["day_01", "--part", "3"]).is_err());
+    }
+
+    #[test]
+    fn answer_as_json() {
+        assert_eq!(answer_json(1, 241861950), r#"{"day":1,"answer":241861950}"#);
+        assert_eq!(answer_json(5, "none"), r#"{"day":5,"answer":"none"}"#);
+    }
+
+    #[test]
+    fn input_option() {
+        let opts = Options::try_parse_from(["day_01", "--input", "Cargo.toml"]).unwrap();
+        let data = load_input(1, &opts).unwrap();
+        assert!(data.contains("aoc_common"));
+        let (_, source) = input_or_fallback(1, &opts, String::new).unwrap();
+        assert_eq!(source, InputSource::Puzzle);
+
+        let opts = Options::try_parse_from(["day_01", "--offline"]).unwrap();
+        let (_, source) = input_or_fallback(1, &opts, String::new).unwrap();
+        assert_eq!(source, InputSource::Fallback);
+    }
+
+    #[test]
+    fn missing_input_file() {
+        let e = get_input_from_file(Path::new("no-such-file.txt")).unwrap_err();
+        assert!(e
+            .to_string()
+            .starts_with("Failed to read \"no-such-file.txt\"."));
+    }
+
+    #[test]
+    fn summary_is_stable() {
+        assert_eq!(input_summary(""), (0, 0xcbf2_9ce4_8422_2325));
+        assert_eq!(input_summary("a"), (1, 0x089b_dc07_b544_e7b2));
+        let (lines, checksum) = input_summary("1721\n979\n366\n");
+        assert_eq!(lines, 3);
+        assert_eq!(input_summary("  1721\r\n979\r\n\r\n366"), (lines, checksum));
+        assert_ne!(input_summary("1721\n366\n979\n").1, checksum);
+    }
+
+    #[test]
+    fn number_in_range() {
+        assert_eq!(parse_in_range("1980", 1920, 2002).unwrap(), 1980);
+        assert_eq!(parse_in_range("1920", 1920, 2002).unwrap(), 1920);
+        assert_eq!(parse_in_range("2002", 1920, 2002).unwrap(), 2002);
+        assert_eq!(parse_in_range("1.5", 1.0, 2.0).unwrap(), 1.5);
+    }
+
+    #[test]
+    fn number_out_of_range() {
+        let e = parse_in_range("1919", 1920, 2002).unwrap_err();
+        assert_eq!(e.to_string(), "1919 is below 1920");
+        let e = parse_in_range("2003", 1920, 2002).unwrap_err();
+        assert_eq!(e.to_string(), "2003 is above 2002");
+    }
+
+    #[test]
+    fn number_not_numeric() {
+        for s in &["abc", "", "19 80", "-5"] {
+            let e = parse_in_range::<usize>(s, 0, 10).unwrap_err();
+            assert_eq!(e.to_string(), format!("\"{}\" is not a number", s));
+        }
+    }
+
+    #[test]
+    fn blocks() {
+        assert_eq!(split_blocks("a b\nc\n\nd\n"), vec!["a b\nc", "d"]);
+        assert_eq!(split_blocks("a\r\nb\r\n\r\n\r\nc\r\n"), vec!["a\r\nb", "c"]);
+        assert_eq!(split_blocks("\n  a\n \t\n\r\n  b  \n\n"), vec!["a", "b"]);
+        assert!(split_blocks("\n\r\n  \n").is_empty());
+        assert!(split_blocks("").is_empty());
+    }
+
+    #[test]
+    fn url_for_day() {
+        assert_eq!(
+            input_url(AOC_YEAR, 5),
+            "https://adventofcode.com/2020/day/5/input"
+        );
+        assert_eq!(
+            input_url(2021, 5),
+            "https://adventofcode.com/2021/day/5/input"
+        );
+    }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::Int(241861950).to_string(), "241861950");
+        assert_eq!(Answer::from(7usize).to_string(), "7");
+        assert_eq!(Answer::Text("abc".to_string()).to_string(), "abc");
+        assert_eq!(Answer::from("").to_string(), "");
+        assert_eq!(answer_json(1, Answer::Int(2)), r#"{"day":1,"answer":2}"#);
+        assert_eq!(
+            answer_json(1, Answer::from("x")),
+            r#"{"day":1,"answer":"x"}"#
+        );
+    }
+
+    #[test]
+    fn error_messages() {
+        let e = AocError::ParseFailed("Bad line.".into());
+        assert_eq!(e.to_string(), "Failed to parse input data. Bad line.");
+    }
+
+    #[test]
+    fn retry_until_success() {
+        let mut calls = 0;
+        let result = retry(3, Duration::from_millis(0), || {
+            calls += 1;
+            if calls < 3 {
+                bail!("attempt {} failed", calls);
+            }
+            Ok(calls)
+        });
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn retry_gives_up() {
+        let mut calls = 0;
+        let result: Result<()> = retry(3, Duration::from_millis(0), || {
+            calls += 1;
+            bail!("attempt {} failed", calls);
+        });
+        assert_eq!(result.unwrap_err().to_string(), "attempt 3 failed");
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn cache_path_for_day() {
+        let dir = Path::new(".aoc-cache");
+        assert_eq!(
+            cache_file(dir, 2020, 3),
+            Path::new(".aoc-cache/2020_day_03.txt")
+        );
+        assert_ne!(cache_file(dir, 2020, 3), cache_file(dir, 2021, 3));
+    }
+}
@@ -0,0 +1,18 @@
+use crate::Answer;
+
+/// Common shape of every day's solution, so all days can be run, timed
+/// and tested the same way. Days without a second part return
+/// `Answer::Text("unimplemented")` from `part2`.
+pub trait Solver {
+    /// Parse the input data. Malformed parts are skipped the same way
+    /// the day does on its own.
+    fn parse(input: &str) -> Self
+    where
+        Self: Sized;
+
+    /// Answer of part 1.
+    fn part1(&self) -> Answer;
+
+    /// Answer of part 2.
+    fn part2(&self) -> Answer;
+}
@@ -0,0 +1,35 @@
+//! Checks that an error page from Advent of Code is not cached as input
+//! data. Environment variables are set for the whole process, so this
+//! lives in its own test binary.
+
+mod common;
+
+use common::{serve, Reply};
+use std::env;
+
+#[test]
+fn only_valid_input_is_cached() {
+    let mut bodies = vec!["<!DOCTYPE html>\n<html></html>\n", "1721\n979\n366\n"].into_iter();
+    let (address, server) = serve(2, move |_| Reply::ok(bodies.next().unwrap()));
+
+    env::set_var("AOC_BASE_URL", address);
+    env::set_var("AOC_SESSION", "abc123");
+    env::set_var(
+        "AOC_CACHE",
+        env::temp_dir().join("aoc_cache_cache_validation"),
+    );
+    let cache = aoc_common::cache_path(1998, 1);
+    let _ = std::fs::remove_file(&cache);
+
+    let first = aoc_common::fetch_input(1998, 1);
+    let cached_after_first = cache.exists();
+    let second = aoc_common::fetch_input(1998, 1);
+    let cached_after_second = std::fs::read_to_string(&cache);
+    let _ = std::fs::remove_file(&cache);
+    server.join().unwrap();
+
+    assert!(first.is_err());
+    assert!(!cached_after_first);
+    assert_eq!(second.unwrap(), "1721\n979\n366\n");
+    assert_eq!(cached_after_second.unwrap(), "1721\n979\n366\n");
+}
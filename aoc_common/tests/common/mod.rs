@@ -0,0 +1,101 @@
+//! Local HTTP server standing in for Advent of Code in tests. Shared by
+//! the integration tests and the unit tests of download.rs, which
+//! include this file with a `#[path]` attribute.
+
+#![allow(dead_code)]
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+/// Response sent by the test server.
+pub struct Reply {
+    status: &'static str,
+    headers: String,
+    body: Vec<u8>,
+}
+
+impl Reply {
+    /// "200 OK" response with given body.
+    pub fn ok<B: Into<Vec<u8>>>(body: B) -> Reply {
+        Reply {
+            status: "200 OK",
+            headers: String::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Response with given status, like "400 Bad Request", and no body.
+    pub fn status(status: &'static str) -> Reply {
+        Reply {
+            status,
+            headers: String::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Add a header, like "Content-Encoding: gzip".
+    pub fn header(mut self, header: &str) -> Reply {
+        self.headers.push_str(header);
+        self.headers.push_str("\r\n");
+        self
+    }
+}
+
+/// Start a server on a free local port. It answers `count` requests, one
+/// per connection, with the reply `respond` gives for each request.
+/// Returns the server address, like "http://127.0.0.1:1234", and the
+/// server thread, which gives back the requests it received.
+pub fn serve<F>(count: usize, mut respond: F) -> (String, JoinHandle<Vec<String>>)
+where
+    F: FnMut(&str) -> Reply + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        (0..count)
+            .map(|_| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let request = read_request(&mut stream);
+                let reply = respond(&request);
+                let head = format!(
+                    "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n",
+                    reply.status,
+                    reply.headers,
+                    reply.body.len()
+                );
+                stream.write_all(head.as_bytes()).unwrap();
+                stream.write_all(&reply.body).unwrap();
+                request
+            })
+            .collect()
+    });
+    (address, server)
+}
+
+/// Read request headers and the body given by Content-Length. Headers
+/// and body may arrive in separate reads.
+fn read_request<R: Read>(stream: &mut R) -> String {
+    let mut request = Vec::new();
+    let mut chunk = [0; 1024];
+    loop {
+        let text = String::from_utf8_lossy(&request);
+        if let Some(end) = text.find("\r\n\r\n") {
+            let length = text[..end]
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(key, _)| key.eq_ignore_ascii_case("Content-Length"))
+                .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            if request.len() >= end + 4 + length {
+                break;
+            }
+        }
+        let len = stream.read(&mut chunk).unwrap();
+        if len == 0 {
+            break;
+        }
+        request.extend_from_slice(&chunk[..len]);
+    }
+    String::from_utf8_lossy(&request).to_string()
+}
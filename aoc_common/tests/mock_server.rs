@@ -0,0 +1,25 @@
+//! Downloads input data from a local server instead of Advent of Code.
+//! Environment variables are set for the whole process, so this lives
+//! in its own test binary.
+
+mod common;
+
+use common::{serve, Reply};
+use std::env;
+
+#[test]
+fn fetch_input_from_mock_server() {
+    let (address, server) = serve(1, |_| Reply::ok("1721\n979\n366\n"));
+
+    env::set_var("AOC_BASE_URL", address);
+    env::set_var("AOC_SESSION", "abc123");
+    env::set_var("AOC_CACHE", env::temp_dir().join("aoc_cache_mock_server"));
+    let _ = std::fs::remove_file(aoc_common::cache_path(1999, 1));
+    let data = aoc_common::fetch_input(1999, 1);
+    let _ = std::fs::remove_file(aoc_common::cache_path(1999, 1));
+    let requests = server.join().unwrap();
+
+    assert_eq!(data.unwrap(), "1721\n979\n366\n");
+    assert!(requests[0].starts_with("GET /1999/day/1/input "));
+    assert!(requests[0].contains("session=abc123"));
+}
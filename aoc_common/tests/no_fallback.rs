@@ -0,0 +1,45 @@
+//! With `--no-fallback`, missing input data is an error instead of the
+//! hard-coded test data. Environment variables are set for the whole
+//! process, so this lives in its own test binary.
+
+use aoc_common::{InputSource, Options};
+use std::env;
+
+fn test_data() -> String {
+    String::from("1721\n979\n366")
+}
+
+#[test]
+fn no_fallback_without_session() {
+    // No session key anywhere and nothing cached for this year.
+    let home = env::temp_dir().join("aoc_no_fallback_home");
+    env::set_var("HOME", &home);
+    env::set_var("USERPROFILE", &home);
+    env::set_var("AOC_SESSION", "");
+    env::set_var("AOC_BASE_URL", "http://127.0.0.1:1");
+    let opts = Options {
+        year: 1997,
+        no_fallback: true,
+        ..Options::default()
+    };
+
+    let e = aoc_common::input_or_fallback(1, &opts, test_data).unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("Input data not available and fallback is disabled."));
+
+    // Offline mode asks for the test data explicitly.
+    let offline = Options {
+        offline: true,
+        ..opts
+    };
+    let (data, source) = aoc_common::input_or_fallback(1, &offline, test_data).unwrap();
+    assert_eq!((data, source), (test_data(), InputSource::Fallback));
+
+    let fallback = Options {
+        year: 1997,
+        ..Options::default()
+    };
+    let (data, source) = aoc_common::input_or_fallback(1, &fallback, test_data).unwrap();
+    assert_eq!((data, source), (test_data(), InputSource::Fallback));
+}
@@ -0,0 +1,44 @@
+//! Prefetches several days from a local server instead of Advent of
+//! Code. Environment variables are set for the whole process, so this
+//! lives in its own test binary.
+
+mod common;
+
+use aoc_common::cache_path;
+use common::{serve, Reply};
+use std::env;
+
+/// Not the default year, so the year given is the one downloaded.
+const YEAR: u32 = 1996;
+
+#[test]
+fn prefetch_caches_every_day() {
+    // Answer with the requested path, like "/1996/day/91/input".
+    let (address, server) = serve(3, |request| {
+        Reply::ok(request.split_whitespace().nth(1).unwrap())
+    });
+
+    env::set_var("AOC_BASE_URL", address);
+    env::set_var("AOC_SESSION", "abc123");
+    env::set_var("AOC_CACHE", env::temp_dir().join("aoc_cache_prefetch"));
+    let days = 91..=93;
+    for day in days.clone() {
+        let _ = std::fs::remove_file(cache_path(YEAR, day));
+    }
+    let results = aoc_common::prefetch_all(YEAR, days.clone());
+    let cached: Vec<String> = days
+        .clone()
+        .map(|day| std::fs::read_to_string(cache_path(YEAR, day)).unwrap_or_default())
+        .collect();
+    for day in days {
+        let _ = std::fs::remove_file(cache_path(YEAR, day));
+    }
+    let requests = server.join().unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|r| r.is_ok()));
+    for (day, data) in (91..).zip(cached.iter()) {
+        assert_eq!(*data, format!("/{}/day/{}/input", YEAR, day));
+    }
+    assert_eq!(requests.len(), 3);
+}
@@ -0,0 +1,27 @@
+//! Submits an answer to a local server instead of Advent of Code.
+//! Environment variables are set for the whole process, so this lives
+//! in its own test binary.
+
+mod common;
+
+use aoc_common::SubmitResult;
+use common::{serve, Reply};
+use std::env;
+
+#[test]
+fn submit_answer_to_mock_server() {
+    let (address, server) = serve(1, |_| {
+        Reply::ok("<article><p>That's the right answer!</p></article>")
+    });
+
+    env::set_var("AOC_BASE_URL", address);
+    env::set_var("AOC_SESSION", "abc123");
+    let result = aoc_common::submit_answer(1999, 1, 2, "241861950");
+    let requests = server.join().unwrap();
+    let request = &requests[0];
+
+    assert_eq!(result.unwrap(), SubmitResult::Correct);
+    assert!(request.starts_with("POST /1999/day/1/answer "));
+    assert!(request.contains("session=abc123"));
+    assert!(request.ends_with("level=2&answer=241861950"));
+}
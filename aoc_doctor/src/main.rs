@@ -0,0 +1,34 @@
+/*!
+# Advent of Code 2020 - Doctor
+
+Checks that the session key can be found and that Advent of Code
+accepts it, before any day silently falls back to the test data.
+
+## Usage example
+
+```text ignore
+PS> cargo run --bin aoc_doctor
+Advent of Code 2020 - Doctor
+Log in to Advent of Code again and update the session key.
+Error: Session key was rejected. Response: 400 Bad Request
+```
+!*/
+
+use anyhow::Result;
+use aoc_common::AocError;
+
+fn main() -> Result<()> {
+    println!("Advent of Code 2020 - Doctor");
+
+    let result = aoc_common::check_session();
+    match result.as_ref().map_err(|e| e.downcast_ref::<AocError>()) {
+        Ok(()) => println!("Session key is valid."),
+        Err(Some(AocError::SessionMissing)) => {}
+        Err(Some(AocError::SessionRejected(_))) => {
+            println!("Log in to Advent of Code again and update the session key.")
+        }
+        Err(_) => println!("Check the network connection and proxy settings."),
+    }
+
+    result
+}
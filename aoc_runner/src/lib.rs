@@ -0,0 +1,96 @@
+/*!
+# Advent of Code 2020 - Runner
+
+Single entry point for solving any implemented day. Input data is loaded
+the same way as when running the day on its own.
+
+## Usage example
+
+```text ignore
+PS> cargo run --bin aoc_runner -- --day 3 --offline
+Advent of Code 2020 - Day 03
+Info: Using hard-coded test data. Offline mode.
+Part 1 answer: 7, Part 2 answer: 336
+```
+!*/
+
+use anyhow::{bail, Result};
+use aoc_common::{Answer, Options, Solver};
+
+/// Solve given day and return the answers of both parts. Days that are
+/// not implemented return an error instead of panicking.
+pub fn run_day(day: u32, opts: &Options) -> Result<(Answer, Answer)> {
+    let answers = match day {
+        1 => solve::<day_01::Day01>(day, opts, day_01::get_input_test)?,
+        2 => solve::<day_02::Day02>(day, opts, day_02::get_input_test)?,
+        3 => solve::<day_03::Day03>(day, opts, day_03::get_input_test)?,
+        4 => solve::<day_04::Day04>(day, opts, day_04::get_input_test)?,
+        5 => solve::<day_05::Day05>(day, opts, day_05::get_input_test)?,
+        _ => bail!("day {} not implemented", day),
+    };
+
+    Ok(answers)
+}
+
+/// Answers of both parts for the hard-coded test data of each day, as
+/// given in the puzzle descriptions. Day N is at index N - 1.
+pub fn sample_answers() -> Vec<(Answer, Answer)> {
+    vec![
+        (Answer::Int(514579), Answer::Int(241861950)),
+        (Answer::Int(2), Answer::Int(1)),
+        (Answer::Int(7), Answer::Int(336)),
+        (Answer::Int(2), Answer::Int(2)),
+        (
+            Answer::Int(820),
+            Answer::from("No missing seat found in input data."),
+        ),
+    ]
+}
+
+/// Load input data for given day and solve both parts with `S`.
+fn solve<S: Solver>(
+    day: u32,
+    opts: &Options,
+    fallback: fn() -> String,
+) -> Result<(Answer, Answer)> {
+    let (input, _) = aoc_common::input_or_fallback(day, opts, fallback)?;
+    let solver = S::parse(&input);
+    Ok((solver.part1(), solver.part2()))
+}
+
+#[cfg(test)]
+mod aoc_runner {
+    use super::*;
+
+    #[test]
+    fn implemented_day() {
+        let opts = Options {
+            offline: true,
+            ..Options::default()
+        };
+        let answers = run_day(3, &opts).unwrap();
+        assert_eq!(answers, sample_answers()[2]);
+    }
+
+    #[test]
+    fn missing_day() {
+        for day in &[0, 6, 25] {
+            let e = run_day(*day, &Options::default()).unwrap_err();
+            assert_eq!(e.to_string(), format!("day {} not implemented", day));
+        }
+    }
+
+    #[test]
+    fn all_days_as_solvers() {
+        let solvers: Vec<Box<dyn Solver>> = vec![
+            Box::new(day_01::Day01::parse(&day_01::get_input_test())),
+            Box::new(day_02::Day02::parse(&day_02::get_input_test())),
+            Box::new(day_03::Day03::parse(&day_03::get_input_test())),
+            Box::new(day_04::Day04::parse(&day_04::get_input_test())),
+            Box::new(day_05::Day05::parse(&day_05::get_input_test())),
+        ];
+        let answers: Vec<(Answer, Answer)> =
+            solvers.iter().map(|s| (s.part1(), s.part2())).collect();
+        assert_eq!(answers, sample_answers());
+    }
+}
@@ -0,0 +1,26 @@
+use anyhow::{bail, Result};
+use aoc_runner::run_day;
+
+fn main() -> Result<()> {
+    aoc_common::init_logger();
+    let opts = aoc_common::Options::from_args();
+    let day = match opts.day {
+        Some(day) => day,
+        None => bail!("Select the day to solve with --day."),
+    };
+
+    let (part1, part2) = run_day(day, &opts)?;
+    if opts.json {
+        let answer = if opts.part == Some(1) { part1 } else { part2 };
+        println!("{}", aoc_common::answer_json(day, answer));
+    } else {
+        println!("Advent of Code 2020 - Day {:02}", day);
+        match opts.part {
+            Some(1) => println!("Part 1 answer: {}", part1),
+            Some(_) => println!("Part 2 answer: {}", part2),
+            None => println!("Part 1 answer: {}, Part 2 answer: {}", part1, part2),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+//! Runs every day in offline mode, so the answers come from the
+//! hard-coded test data without network or a session key.
+
+use aoc_common::Options;
+use aoc_runner::{run_day, sample_answers};
+
+#[test]
+fn all_days_offline() {
+    let opts = Options {
+        offline: true,
+        ..Options::default()
+    };
+    for (day, answers) in (1..).zip(sample_answers().iter()) {
+        assert_eq!(run_day(day, &opts).unwrap(), *answers, "day {}", day);
+    }
+}
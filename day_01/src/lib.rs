@@ -0,0 +1,421 @@
+/*!
+# Advent of Code 2020 - Day 01
+[Link to task.](https://adventofcode.com/2020/day/1)
+
+Part 1: Find two values from list where:
+
+```text ignore
+value_1 + value_2 == 2020
+```
+
+Part 2: Find three values from list where:
+
+```text ignore
+value_1 + value_2 + value_3 == 2020
+```
+
+Correct answers for website are calculated by multiplying the
+found values together.
+
+## Usage example
+
+```text ignore
+PS> cargo run --bin day_01
+    Finished dev [unoptimized + debuginfo] target(s) in 0.15s
+    Running `target\debug\day_01.exe`
+Advent of Code 2020 - Day 01
+Info: Using hard-coded test data. ".aoc-session" not found.
+Part 1 values: 1721 + 299 = 2020.
+Part 1 answer: 514579.
+Part 2 values: 979 + 366 + 675 = 2020.
+Part 2 answer: 241861950.
+```
+!*/
+
+use anyhow::{bail, Result};
+use aoc_common::{Answer, InputSource, Options, Solver};
+use log::warn;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+static AOC_DAY: u32 = 1;
+
+/// If input data download was not available, this function
+/// returns hardcoded test data which is allowed to be shared.
+pub fn get_input_test() -> String {
+    String::from(
+        "1721
+        979
+        366
+        299
+        675
+        1456",
+    )
+    .to_owned()
+}
+
+/// Get input data either from AOC website or fall-back to local
+/// hard-coded test data.
+pub fn get_input(opts: &Options) -> Result<(Vec<u64>, InputSource)> {
+    let (input, source) = aoc_common::input_or_fallback(AOC_DAY, opts, get_input_test)?;
+
+    Ok((parse_numbers(&input), source))
+}
+
+/// Parse input data to numbers separated by any whitespace or commas, so
+/// pasted data doesn't need to have one number per line. Tokens that are
+/// not numbers are counted and reported once as a warning.
+pub fn parse_numbers(input: &str) -> Vec<u64> {
+    let mut data = Vec::new();
+    let mut failures = 0;
+
+    let tokens = input.split(|c: char| c.is_whitespace() || c == ',');
+    for token in tokens.filter(|s| !s.is_empty()) {
+        match token.parse::<u64>() {
+            Ok(value) => data.push(value),
+            Err(_) => failures += 1,
+        }
+    }
+
+    if failures > 0 {
+        warn!("Skipped {} non-number values in input data.", failures);
+    }
+    data
+}
+
+/// Calculate correct answer for part 1. Keeps a set of already seen
+/// values and checks whether the complement of each value is in it.
+/// A value is only added to the set after the check, so target / 2 is
+/// paired with itself only when it appears twice in the input.
+pub fn day_01(input: Vec<u64>, target: u64) -> Result<(u64, u64)> {
+    let mut seen: HashSet<u64> = HashSet::new();
+    for val in input {
+        if val <= target && seen.contains(&(target - val)) {
+            return Ok((target - val, val));
+        }
+        seen.insert(val);
+    }
+    bail!("no pair sums to {}", target)
+}
+
+/// Alternative to day_01. Sorts the values and walks inward from both
+/// ends: too small a sum moves the low end up, too large moves the high
+/// end down. Takes O(n log n) time for the sort but no extra memory,
+/// while the HashSet version takes O(n) time and O(n) memory. Returns
+/// the pair smaller value first.
+pub fn day_01_two_pointer(mut input: Vec<u64>, target: u64) -> Option<(u64, u64)> {
+    input.sort_unstable();
+    if input.is_empty() {
+        return None;
+    }
+
+    let (mut low, mut high) = (0, input.len() - 1);
+    while low < high {
+        // Overflowing sum is too large as well.
+        match input[low].checked_add(input[high]) {
+            Some(sum) if sum == target => return Some((input[low], input[high])),
+            Some(sum) if sum < target => low += 1,
+            _ => high -= 1,
+        }
+    }
+    None
+}
+
+/// Brute force version of day_01 that does less wasted work. Sorts the
+/// values first, so the input is taken by value and consumed. Each value
+/// is only compared with the values after it, never with itself, and the
+/// inner loop stops as soon as the sum exceeds `target` since every
+/// following value is at least as large. Returns the pair smaller value
+/// first.
+pub fn day_01_brute_force(mut input: Vec<u64>, target: u64) -> Option<(u64, u64)> {
+    input.sort_unstable();
+    for (i, val1) in input.iter().enumerate() {
+        for val2 in &input[i + 1..] {
+            match val1.checked_add(*val2) {
+                Some(sum) if sum == target => return Some((*val1, *val2)),
+                Some(sum) if sum < target => {}
+                _ => break,
+            }
+        }
+    }
+    None
+}
+
+/// Count unordered pairs of distinct indices whose values sum to
+/// `target`. Keeps count of already seen values, so each value pairs
+/// with every earlier occurrence of its complement exactly once.
+pub fn count_pairs(input: &[u64], target: u64) -> usize {
+    let mut seen: HashMap<u64, usize> = HashMap::new();
+    let mut pairs = 0;
+    for val in input {
+        if let Some(complement) = target.checked_sub(*val) {
+            pairs += seen.get(&complement).copied().unwrap_or(0);
+        }
+        *seen.entry(*val).or_insert(0) += 1;
+    }
+    pairs
+}
+
+/// Find `k` values with distinct indices that sum to `target`.
+/// Recursively picks one value and searches the rest of the list
+/// for `k - 1` values summing to the remainder.
+pub fn k_sum(input: &[u64], k: usize, target: u64) -> Option<Vec<u64>> {
+    if k == 0 {
+        return if target == 0 { Some(Vec::new()) } else { None };
+    }
+    for (i, val) in input.iter().enumerate() {
+        if *val > target {
+            continue;
+        }
+        if let Some(mut rest) = k_sum(&input[i + 1..], k - 1, target - val) {
+            rest.insert(0, *val);
+            return Some(rest);
+        }
+    }
+    None
+}
+
+/// Calculate correct answer for part 2. Uses brute force search.
+/// Each index is used at most once. Returns (0, 0, 0) if no
+/// three values sum to 2020.
+pub fn day_01_part2(input: Vec<u64>) -> (u64, u64, u64) {
+    match k_sum(&input, 3, 2020) {
+        Some(values) => (values[0], values[1], values[2]),
+        None => (0, 0, 0),
+    }
+}
+
+/// Values found summing to the target, together with their product
+/// which is the answer for the website.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day01Answer {
+    pub values: Vec<u64>,
+    pub product: u64,
+}
+
+impl Day01Answer {
+    /// Create answer from found values, calculating the product once.
+    /// Returns None if the product doesn't fit in u64.
+    pub fn new(values: Vec<u64>) -> Option<Day01Answer> {
+        let product = values.iter().try_fold(1u64, |acc, v| acc.checked_mul(*v))?;
+        Some(Day01Answer { values, product })
+    }
+}
+
+/// Prints the values the same way as the sum, "1721 + 299 = 2020".
+impl fmt::Display for Day01Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let values: Vec<String> = self.values.iter().map(|v| v.to_string()).collect();
+        // Summed as u128 so any number of u64 values fits.
+        let sum: u128 = self.values.iter().map(|v| *v as u128).sum();
+        write!(f, "{} = {}", values.join(" + "), sum)
+    }
+}
+
+/// Find `count` values summing to `target`. Pairs are searched with
+/// day_01 and larger counts with k_sum. Returns None if there are no
+/// such values or their product doesn't fit in u64.
+pub fn solve(input: &[u64], count: usize, target: u64) -> Option<Day01Answer> {
+    let values = match count {
+        2 => day_01(input.to_vec(), target).ok().map(|(a, b)| vec![a, b]),
+        _ => k_sum(input, count, target),
+    };
+    values.and_then(Day01Answer::new)
+}
+
+/// Day 1 for the common Solver interface.
+pub struct Day01 {
+    numbers: Vec<u64>,
+}
+
+impl Solver for Day01 {
+    fn parse(input: &str) -> Day01 {
+        Day01 {
+            numbers: parse_numbers(input),
+        }
+    }
+
+    fn part1(&self) -> Answer {
+        match solve(&self.numbers, 2, 2020) {
+            Some(answer) => Answer::from(answer.product),
+            None => Answer::Text(String::from("no pair sums to 2020")),
+        }
+    }
+
+    fn part2(&self) -> Answer {
+        match solve(&self.numbers, 3, 2020) {
+            Some(answer) => Answer::from(answer.product),
+            None => Answer::Text(String::from("no three values sum to 2020")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod day_01 {
+    use super::*;
+
+    #[test]
+    fn parse_skips_blank_and_bad_lines() {
+        assert_eq!(
+            parse_numbers("1721\n979\n\nabc\n366\n"),
+            vec![1721, 979, 366]
+        );
+    }
+
+    #[test]
+    fn parse_mixed_separators() {
+        assert_eq!(
+            parse_numbers("1721, 979 366\n299"),
+            vec![1721, 979, 366, 299]
+        );
+        assert_eq!(parse_numbers("1721,,979\r\n x \n"), vec![1721, 979]);
+        assert_eq!(parse_numbers(&get_input_test()).len(), 6);
+    }
+
+    #[test]
+    fn parse_with_bom() {
+        let input = aoc_common::normalize_input("\u{feff}1721\r\n979\r\n366\r\n");
+        assert_eq!(parse_numbers(&input), vec![1721, 979, 366]);
+    }
+
+    #[test]
+    fn parse_from_file() {
+        let path = std::env::temp_dir().join("aoc_day_01_input.txt");
+        std::fs::write(&path, "1721\n979\n366\n").unwrap();
+        let data = aoc_common::get_input_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(parse_numbers(&data), vec![1721, 979, 366]);
+    }
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(
+            day_01(vec![1721, 979, 366, 299, 675, 1456], 2020).unwrap(),
+            (1721, 299)
+        );
+    }
+
+    #[test]
+    fn part1_single_1010_is_not_a_pair() {
+        assert!(day_01(vec![1010, 5, 7], 2020).is_err());
+        assert_eq!(day_01(vec![1010, 5, 1010], 2020).unwrap(), (1010, 1010));
+    }
+
+    #[test]
+    fn part1_small_target() {
+        assert_eq!(day_01(vec![1, 4, 8, 6], 10).unwrap(), (4, 6));
+        assert!(day_01(vec![], 10).is_err());
+    }
+
+    #[test]
+    fn part1_no_solution_error() {
+        let e = day_01(vec![1, 4, 8, 5], 10).unwrap_err();
+        assert_eq!(e.to_string(), "no pair sums to 10");
+    }
+
+    #[test]
+    fn large_values_do_not_overflow() {
+        let max = u32::MAX as u64;
+        assert!(day_01(vec![max, max - 1], 2020).is_err());
+        assert_eq!(day_01_part2(vec![max, max - 1, u64::MAX]), (0, 0, 0));
+        assert_eq!(day_01_two_pointer(vec![1, u64::MAX], 2020), None);
+    }
+
+    #[test]
+    fn two_pointer_matches() {
+        let input = vec![1721, 979, 366, 299, 675, 1456];
+        let (a, b) = day_01(input.clone(), 2020).unwrap();
+        assert_eq!(
+            day_01_two_pointer(input.clone(), 2020),
+            Some((a.min(b), a.max(b)))
+        );
+        assert_eq!(k_sum(&input, 2, 2020), Some(vec![1721, 299]));
+        assert_eq!(day_01_two_pointer(vec![1010, 5], 2020), None);
+        assert_eq!(day_01_two_pointer(vec![], 2020), None);
+    }
+
+    #[test]
+    fn brute_force_matches_unsorted() {
+        let input = vec![1721, 979, 366, 299, 675, 1456];
+        let unsorted = k_sum(&input, 2, 2020).unwrap();
+        assert_eq!(
+            day_01_brute_force(input, 2020),
+            Some((unsorted[1], unsorted[0]))
+        );
+        assert_eq!(day_01_brute_force(vec![1010, 5], 2020), None);
+        assert_eq!(
+            day_01_brute_force(vec![1010, 5, 1010], 2020),
+            Some((1010, 1010))
+        );
+        assert_eq!(day_01_brute_force(vec![1, u64::MAX], 2020), None);
+    }
+
+    #[test]
+    fn count_pairs_with_duplicates() {
+        assert_eq!(count_pairs(&[1010, 1010, 5], 2020), 1);
+        assert_eq!(count_pairs(&[1010, 1010, 1010], 2020), 3);
+        assert_eq!(count_pairs(&[1010, 5], 2020), 0);
+        assert_eq!(count_pairs(&[1, 9, 9, 1, 5], 10), 4);
+        assert_eq!(count_pairs(&[1721, 979, 366, 299, 675, 1456], 2020), 1);
+    }
+
+    #[test]
+    fn k_sum_pair() {
+        let input = [1721, 979, 366, 299, 675, 1456];
+        assert_eq!(k_sum(&input, 2, 2020), Some(vec![1721, 299]));
+    }
+
+    #[test]
+    fn k_sum_triple() {
+        let input = [1721, 979, 366, 299, 675, 1456];
+        assert_eq!(k_sum(&input, 3, 2020), Some(vec![979, 366, 675]));
+    }
+
+    #[test]
+    fn k_sum_no_solution() {
+        assert_eq!(k_sum(&[1, 2, 3], 2, 10), None);
+        assert_eq!(k_sum(&[5], 2, 10), None);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let (a, b, c) = day_01_part2(vec![1721, 979, 366, 299, 675, 1456]);
+        assert_eq!(a * b * c, 241861950);
+    }
+
+    #[test]
+    fn answer_struct() {
+        let input = parse_numbers(&get_input_test());
+        let answer = solve(&input, 3, 2020).unwrap();
+        assert_eq!(answer.values, vec![979, 366, 675]);
+        assert_eq!(answer.product, 241861950);
+        assert_eq!(answer.to_string(), "979 + 366 + 675 = 2020");
+        assert_eq!(solve(&input, 2, 2020).unwrap().product, 514579);
+        assert_eq!(solve(&[1, 2, 3], 2, 2020), None);
+    }
+
+    #[test]
+    fn answer_struct_large_values() {
+        assert_eq!(solve(&[4_000_000_000; 3], 3, 12_000_000_000), None);
+        assert_eq!(Day01Answer::new(vec![u64::MAX, 2]), None);
+        let answer = Day01Answer::new(vec![u64::MAX, 1]).unwrap();
+        assert_eq!(answer.product, u64::MAX);
+        assert_eq!(
+            answer.to_string(),
+            "18446744073709551615 + 1 = 18446744073709551616"
+        );
+    }
+
+    #[test]
+    fn solver_without_answer() {
+        let day = Day01::parse("1010 505 7");
+        assert_eq!(day.part1(), Answer::from("no pair sums to 2020"));
+        assert_eq!(day.part2(), Answer::from("no three values sum to 2020"));
+    }
+
+    #[test]
+    fn part2_does_not_reuse_index() {
+        // 1010 + 505 + 505 would only sum to 2020 by reusing 505.
+        assert_eq!(day_01_part2(vec![1010, 505, 7]), (0, 0, 0));
+    }
+}
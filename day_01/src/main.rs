@@ -1,142 +1,69 @@
-/*!
-# Advent of Code 2020 - Day 01
-[Link to task.](https://adventofcode.com/2020/day/1)
-
-Find three values from list where:
-
-```text ignore
-value_1 + value_2 + value_3 == 2020
-```
-
-Correct answer for website is calculated by:
-
-```text ignore
-value_1 * value_2 * value_3.
-```
-
-## Usage example
-
-```text ignore
-PS> cargo run --bin day_01
-    Finished dev [unoptimized + debuginfo] target(s) in 0.15s
-    Running `target\debug\day_01.exe`
-Advent of Code 2020 - Day 01
-Info: Using hard-coded test data. ".aoc-session" not found.
-Correct values: 979 + 366 + 675 = 2020.
-Answer: 241861950.
-```
-!*/
-
 use anyhow::{bail, Result};
-use reqwest;
-use std::fs::read_to_string;
-use std::path::Path;
-
-static AOC_URL: &'static str = "https://adventofcode.com/2020/day/1/input";
-static AOC_SESSION_FILE: &'static str = ".aoc-session";
+use aoc_common::{AocError, Options};
+use day_01::{get_input, solve};
+use std::time::Instant;
 
-/// This function downloads input data from Advent of Code
-/// if .aoc-session file is available and download succeeds.
-fn get_input_aoc() -> Result<String> {
-    let f = Path::new(&AOC_SESSION_FILE);
-
-    if !f.is_file() {
-        bail!("{:?} not found.", &AOC_SESSION_FILE);
-    }
-
-    // Load session key
-    let session_key = read_to_string(f)?;
-
-    // Load input data
-    let client = reqwest::blocking::Client::new();
-    let response = client
-        .get(AOC_URL)
-        .header("Cookie", format!("session={}", session_key))
-        .send()
-        .expect("Sending request failed.");
-
-    if response.status().is_success() {
-        let resp = response.text()?;
-        return Ok(resp);
-    } else {
-        bail!(
-            "Failed to load {:?}. Response: {:?}",
-            &AOC_URL,
-            response.status()
-        )
-    }
+fn main() -> Result<()> {
+    aoc_common::init_logger();
+    run(&Options::from_args())
 }
 
-/// If input data download was not available, this function
-/// returns hardcoded test data which is allowed to be shared.
-fn get_input_test() -> String {
-    String::from(
-        "1721
-        979
-        366
-        299
-        675
-        1456",
-    )
-    .to_owned()
-}
-
-/// Get input data either from AOC website or fall-back to local
-/// hard-coded test data.
-pub fn get_input() -> Vec<u32> {
-    let input: String = match get_input_aoc() {
-        Ok(data) => {
-            println!("Info: Downloaded test data from: {}", AOC_URL);
-            data
+/// Solve the day with given options.
+fn run(opts: &Options) -> Result<()> {
+    if !opts.json {
+        println!("Advent of Code 2020 - Day 01");
+    }
+    let (input, source) = get_input(opts)?;
+    let start = Instant::now();
+
+    let part1 = solve(&input, 2, 2020);
+    let part2 = solve(&input, 3, 2020);
+    if opts.json {
+        let answer = if opts.part == Some(1) { &part1 } else { &part2 };
+        match answer {
+            Some(answer) => println!("{}", aoc_common::answer_json(1, answer.product)),
+            None => bail!(AocError::NoSolution),
         }
-        Err(e) => {
-            println!("Info: Using hard-coded test data. {}", e);
-            get_input_test()
+    } else {
+        if opts.show_part(1) {
+            match &part1 {
+                Some(answer) => {
+                    println!("Part 1 values: {}.", answer);
+                    println!("Part 1 answer: {}.", answer.product);
+                }
+                None => println!("Part 1: no pair sums to 2020."),
+            }
         }
-    };
-
-    let data = input
-        .lines()
-        .map(|s| {
-            s.trim()
-                .parse::<u32>()
-                .expect("Input data contained non-number value.")
-        })
-        .collect();
-    return data;
-}
-
-/// Calculate correct answer. Uses brute force search.
-pub fn day_01(input: Vec<u32>) -> (u32, u32, u32) {
-    for val1 in input.iter() {
-        for val2 in input.iter() {
-            for val3 in input.iter() {
-                if val1 + val2 + val3 == 2020 {
-                    return (val1.clone(), val2.clone(), val3.clone());
+        if opts.show_part(2) {
+            match &part2 {
+                Some(answer) => {
+                    println!("Part 2 values: {}.", answer);
+                    println!("Part 2 answer: {}.", answer.product);
                 }
+                None => println!("Part 2: no triple sums to 2020."),
             }
         }
     }
-    return (0, 0, 0);
-}
-
-fn main() {
-    println!("Advent of Code 2020 - Day 01");
-
-    let (val1, val2, val3) = day_01(get_input());
-    assert_eq!(val1 + val2 + val3, 2020);
-    let answer = val1 * val2 * val3;
+    aoc_common::print_solve_time(opts, start);
+    for (part, answer) in [(1, &part1), (2, &part2)].iter() {
+        if let Some(answer) = answer {
+            aoc_common::record_answer(source, 1, *part, &answer.product.to_string());
+        }
+    }
 
-    println!("Correct values: {} + {} + {} = 2020.", val1, val2, val3);
-    println!("Answer: {}.", answer);
+    Ok(())
 }
 
 #[cfg(test)]
-mod day_01 {
+mod tests {
     use super::*;
 
     #[test]
-    fn run() {
-        main();
+    fn run_offline() {
+        let opts = Options {
+            offline: true,
+            ..Options::default()
+        };
+        run(&opts).unwrap();
     }
 }
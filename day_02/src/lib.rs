@@ -0,0 +1,321 @@
+/*!
+# Advent of Code 2020 - Day 02
+[Link to task.](https://adventofcode.com/2020/day/2)
+
+How many password are valid based on password policies at the time?
+
+Input file is in rows similar to "1-3 a: abcde". Number range implies how many
+letters there has to be. After semicolon is the password itself. In this example
+atleast 1, but at most 3, instances of letter "a" is allowed on the password "abcde".
+The example password is thus valid.
+
+Go through input data and validate all password. Count valid passwords.
+
+In part 2 the numbers are positions (starting from 1) in the password instead.
+Exactly one of the positions must contain the letter. Positions and counts are
+in characters, not bytes.
+
+## Usage example
+
+```text ignore
+PS> cargo run --bin day_02
+   Compiling day_02 v0.1.0 (...\advent_of_code_2020\day_02)
+    Finished dev [unoptimized + debuginfo] target(s) in 1.81s
+     Running `target\debug\day_02.exe`
+Advent of Code 2020 - Day 02
+Info: Using hard-coded test data. ".aoc-session" not found.
+Part 1 answer: 2 valid passwords in input data.
+Part 2 answer: 1 valid passwords in input data.
+```
+!*/
+
+use anyhow::{bail, Result};
+use aoc_common::{Answer, InputSource, Options, Solver};
+use log::warn;
+
+static AOC_DAY: u32 = 2;
+
+#[derive(Debug)]
+pub struct PassPolicy {
+    required_letter: char,
+    pos_1: u32,
+    pos_2: u32,
+}
+
+/// Which password policy to validate against.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PolicyMode {
+    /// Part 1. The required letter appears at least pos_1 and at most
+    /// pos_2 times.
+    CountRange,
+    /// Part 2. Exactly one of the positions pos_1 and pos_2 has the
+    /// required letter.
+    Positions,
+}
+
+#[derive(Debug)]
+pub struct PassInstance {
+    policy: PassPolicy,
+    password: String,
+}
+
+impl PassInstance {
+    pub fn from_string(txt: &str) -> Result<PassInstance> {
+        // Split string to amount, required letter and password parts.
+        let parts: Vec<&str> = txt.split_whitespace().collect();
+        if parts.len() != 3 {
+            bail!("Malformed line {:?}. Expected 3 parts.", txt);
+        }
+
+        let (pos_1, pos_2) = match parts[0].split_once('-') {
+            Some(charpos) => charpos,
+            None => bail!("Malformed password policy {:?}.", parts[0]),
+        };
+        let pos_1: u32 = match pos_1.parse() {
+            Ok(pos) => pos,
+            Err(_) => bail!("Failed to parse password policy {:?}.", parts[0]),
+        };
+        let pos_2: u32 = match pos_2.parse() {
+            Ok(pos) => pos,
+            Err(_) => bail!("Failed to parse password policy {:?}.", parts[0]),
+        };
+        let required_letter = match parts[1].replace(":", "").chars().next() {
+            Some(letter) => letter,
+            None => bail!("Missing required letter in {:?}.", txt),
+        };
+
+        Ok(PassInstance {
+            policy: PassPolicy {
+                required_letter,
+                pos_1,
+                pos_2,
+            },
+            password: parts[2].to_owned(),
+        })
+    }
+
+    /// Check the password against the policy of given mode.
+    pub fn is_valid(&self, mode: PolicyMode) -> bool {
+        match mode {
+            PolicyMode::CountRange => self.is_valid_count(),
+            PolicyMode::Positions => self.is_valid_positions(),
+        }
+    }
+
+    /// Part 1 policy. Password is valid when it contains the required
+    /// letter at least pos_1 and at most pos_2 times.
+    fn is_valid_count(&self) -> bool {
+        let count = self
+            .password
+            .chars()
+            .filter(|c| *c == self.policy.required_letter)
+            .count() as u32;
+        count >= self.policy.pos_1 && count <= self.policy.pos_2
+    }
+
+    /// Part 2 policy. Password is valid when exactly one of the positions
+    /// pos_1 and pos_2 has the required letter. Positions index characters,
+    /// not bytes, so multi-byte letters count as one position.
+    fn is_valid_positions(&self) -> bool {
+        let chars: Vec<char> = self.password.chars().collect();
+        let has_letter = |pos: u32| {
+            (pos as usize).checked_sub(1).and_then(|i| chars.get(i))
+                == Some(&self.policy.required_letter)
+        };
+
+        // Password is valid when exactly one position is required_letter.
+        has_letter(self.policy.pos_1) ^ has_letter(self.policy.pos_2)
+    }
+}
+
+/// If input data download was not available, this function
+/// returns hardcoded test data which is allowed to be shared.
+pub fn get_input_test() -> String {
+    String::from(
+        "1-3 a: abcde
+        1-3 b: cdefg
+        2-9 c: ccccccccc",
+    )
+    .to_owned()
+}
+
+/// Get input data either from AOC website or fall-back to local
+/// hard-coded test data.
+pub fn get_input(opts: &Options) -> Result<(Vec<String>, InputSource)> {
+    let (input, source) = aoc_common::input_or_fallback(AOC_DAY, opts, get_input_test)?;
+
+    let data = input.lines().map(|s| s.trim().to_string()).collect();
+    Ok((data, source))
+}
+
+/// Parse input lines to PassInstances. Blank lines are skipped and
+/// malformed lines are reported as warnings. Input lines are borrowed,
+/// only the passwords are copied.
+pub fn parse_input(input: &[String]) -> Vec<PassInstance> {
+    let (output, errors) = parse_input_verbose(input);
+    for (line_number, e) in errors {
+        warn!("Skipped line {}. {}", line_number, e);
+    }
+    output
+}
+
+/// Parse input lines to PassInstances and list the malformed lines as
+/// (line number, error). Line numbers start from 1 and blank lines are
+/// counted but skipped.
+pub fn parse_input_verbose(input: &[String]) -> (Vec<PassInstance>, Vec<(usize, String)>) {
+    let mut output: Vec<PassInstance> = Vec::new();
+    let mut errors: Vec<(usize, String)> = Vec::new();
+    for (num, line) in input.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match PassInstance::from_string(line) {
+            Ok(instance) => output.push(instance),
+            Err(e) => errors.push((num + 1, e.to_string())),
+        }
+    }
+    (output, errors)
+}
+
+/// Count passwords valid by the policy of given mode.
+pub fn count_valid_passwords(input: &[PassInstance], mode: PolicyMode) -> u32 {
+    let amount: u32 = input.iter().filter(|x| x.is_valid(mode)).count() as u32;
+    amount
+}
+
+/// Count passwords valid by part 1 and part 2 policies in one pass.
+/// Returns (part 1 count, part 2 count).
+pub fn count_both(input: &[PassInstance]) -> (u32, u32) {
+    input.iter().fold((0, 0), |(part1, part2), x| {
+        (
+            part1 + x.is_valid(PolicyMode::CountRange) as u32,
+            part2 + x.is_valid(PolicyMode::Positions) as u32,
+        )
+    })
+}
+
+/// Day 2 for the common Solver interface.
+pub struct Day02 {
+    passwords: Vec<PassInstance>,
+}
+
+impl Solver for Day02 {
+    fn parse(input: &str) -> Day02 {
+        let lines: Vec<String> = input.lines().map(|s| s.trim().to_string()).collect();
+        Day02 {
+            passwords: parse_input(&lines),
+        }
+    }
+
+    fn part1(&self) -> Answer {
+        Answer::from(count_valid_passwords(
+            &self.passwords,
+            PolicyMode::CountRange,
+        ))
+    }
+
+    fn part2(&self) -> Answer {
+        Answer::from(count_valid_passwords(
+            &self.passwords,
+            PolicyMode::Positions,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod day_02 {
+    use super::*;
+
+    #[test]
+    fn count_policy() {
+        assert!(PassInstance::from_string("1-3 a: abcde")
+            .unwrap()
+            .is_valid(PolicyMode::CountRange));
+        assert!(!PassInstance::from_string("1-3 b: cdefg")
+            .unwrap()
+            .is_valid(PolicyMode::CountRange));
+        assert!(PassInstance::from_string("2-9 c: ccccccccc")
+            .unwrap()
+            .is_valid(PolicyMode::CountRange));
+    }
+
+    #[test]
+    fn modes_sample() {
+        let input: Vec<String> = get_input_test()
+            .lines()
+            .map(|s| s.trim().to_string())
+            .collect();
+        let passwords = parse_input(&input);
+        assert_eq!(count_valid_passwords(&passwords, PolicyMode::CountRange), 2);
+        assert_eq!(count_valid_passwords(&passwords, PolicyMode::Positions), 1);
+    }
+
+    #[test]
+    fn both_counts_sample() {
+        let input: Vec<String> = get_input_test()
+            .lines()
+            .map(|s| s.trim().to_string())
+            .collect();
+        assert_eq!(count_both(&parse_input(&input)), (2, 1));
+        // Input is still usable after parsing.
+        assert_eq!(input.len(), 3);
+    }
+
+    #[test]
+    fn unicode_password() {
+        // Positions index characters, "ä" is two bytes but one position.
+        let valid = |line: &str, mode| PassInstance::from_string(line).unwrap().is_valid(mode);
+        assert!(valid("2-3 b: äbc", PolicyMode::Positions));
+        assert!(!valid("1-2 ä: ää", PolicyMode::Positions));
+        assert!(valid("2-2 ä: äbä", PolicyMode::CountRange));
+    }
+
+    #[test]
+    fn position_zero_does_not_panic() {
+        assert!(PassInstance::from_string("0-1 a: ab")
+            .unwrap()
+            .is_valid(PolicyMode::Positions));
+    }
+
+    #[test]
+    fn short_line() {
+        let e = PassInstance::from_string("1-3 a:").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Malformed line \"1-3 a:\". Expected 3 parts."
+        );
+    }
+
+    #[test]
+    fn non_numeric_count() {
+        let e = PassInstance::from_string("x-3 a: abcde").unwrap_err();
+        assert_eq!(e.to_string(), "Failed to parse password policy \"x-3\".");
+    }
+
+    #[test]
+    fn bad_line_is_reported() {
+        let input: Vec<String> = ["1-3 a: abcde", "", "1-3 b cdefg x", "2-9 c: ccccccccc"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let (passwords, errors) = parse_input_verbose(&input);
+        assert_eq!(passwords.len(), 2);
+        assert_eq!(
+            errors,
+            vec![(
+                3,
+                "Malformed line \"1-3 b cdefg x\". Expected 3 parts.".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn bad_lines_are_skipped() {
+        let input = vec![
+            "1-3 a: abcde".to_string(),
+            "".to_string(),
+            "oops".to_string(),
+        ];
+        assert_eq!(parse_input(&input).len(), 1);
+    }
+}
@@ -0,0 +1,527 @@
+/*!
+# Advent of Code 2020 - Day 03
+[Link to task.](https://adventofcode.com/2020/day/3)
+
+How many trees do you encounter on your journey? Try multiple routes
+and multiply their tree-counts together to get the answer.
+
+Starting from top-left corner (x=0, y=0) and using the following map
+(which repeats infinitely sideways), where # represents a tree:
+
+```text ignore
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+```
+
+You travel 3 steps right and 1 step left. If the position where arrive
+is a tree, increase the count of trees. Continue until you have arrived
+on the lowest line (y=10) on the map.
+
+Repeat for different travel patterns. Multiply all resulting counts of
+trees together.
+
+
+## Usage example
+
+```text ignore
+PS> cargo run --bin day_03 -- --offline
+   Compiling day_03 v0.1.0 (...\advent_of_code_2020\day_03)
+    Finished dev [unoptimized + debuginfo] target(s) in 1.68s
+     Running `target\debug\day_03.exe --offline`
+Advent of Code 2020 - Day 03
+Info: Using hard-coded test data. Offline mode.
+Part 1 answer: 7 trees encountered with slope right 3, down 1.
+Part 2 answer: 336 trees encountered while travelling.
+```
+
+## Notes / TODO
+
+After implementing this solution I realized that it would be possible
+to just get .lines().nth(n) and .chars().nth(n) of the input and then
+compare the character at that position to see whether to add to tree
+count or not. This would've been much more elegant solution, atleast
+on memory usage persepective. That solution is count_trees_lazy.
+!*/
+
+use anyhow::{bail, Result};
+use aoc_common::{Answer, Grid, InputSource, Options, Solver};
+
+static AOC_DAY: u32 = 3;
+
+/// If input data download was not available, this function
+/// returns hardcoded test data which is allowed to be shared.
+pub fn get_input_test() -> String {
+    String::from(
+        "..##.......
+        #...#...#..
+        .#....#..#.
+        ..#.#...#.#
+        .#...##..#.
+        ..#.##.....
+        .#.#.#....#
+        .#........#
+        #.##...#...
+        #...##....#
+        .#..#...#.#",
+    )
+    .to_owned()
+}
+
+/// Get input data either from AOC website or fall-back to local
+/// hard-coded test data.
+pub fn get_input(opts: &Options) -> Result<(String, InputSource)> {
+    aoc_common::input_or_fallback(AOC_DAY, opts, get_input_test)
+}
+
+#[derive(Clone)]
+struct Coords {
+    x: isize,
+    y: isize,
+}
+/// Map where a tree is 1 and open ground 0, so trees can be summed.
+#[derive(Clone)]
+pub struct TobogganMap {
+    grid: Grid<usize>,
+    pos: Coords,
+}
+
+impl TobogganMap {
+    /// Create map instance from string representation of the map.
+    pub fn from_string_map(mapstr: &str) -> TobogganMap {
+        TobogganMap::from_string_map_with(mapstr, '#')
+    }
+
+    /// Create map instance from string representation of the map where
+    /// `tree` marks a tree. Any other character is open ground.
+    pub fn from_string_map_with(mapstr: &str, tree: char) -> TobogganMap {
+        TobogganMap {
+            grid: Grid::from_lines(mapstr, |c| if c == tree { 1 } else { 0 }),
+            pos: Coords { x: 0, y: 0 },
+        }
+    }
+
+    /// Create map instance like from_string_map, but return error if the
+    /// map is empty or its rows are not all the same width. Moves wrap
+    /// around the widest row, so shorter rows would be read out of bounds.
+    pub fn from_string_map_checked(mapstr: &str) -> Result<TobogganMap> {
+        let map = TobogganMap::from_string_map(mapstr);
+        if map.width() == 0 {
+            bail!("Map is empty")
+        }
+        for (rownum, row) in map.grid.rows().enumerate() {
+            if row.len() != map.width() {
+                bail!(
+                    "Row {} is {} wide, expected {}",
+                    rownum,
+                    row.len(),
+                    map.width()
+                )
+            }
+        }
+        Ok(map)
+    }
+
+    /// Width of the map, before it repeats on x-axis.
+    pub fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    /// Number of rows on the map.
+    pub fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    /// Move on the map and return the value on the new position.
+    /// Map will repeat infinitely on x-axis, but not on y.
+    pub fn move_by(&mut self, x: isize, y: isize) -> Result<usize> {
+        // Wrap x around the map width, which is allowed for 'infinite scrolling'.
+        // Works for moves of any length in either direction.
+        self.pos.x = (self.pos.x + x).rem_euclid(self.width().max(1) as isize);
+
+        // Check for y overflow or underflow, which is not allowed.
+        if self.pos.y + y >= self.height() as isize || self.pos.y + y < 0 {
+            bail!("Illegal move")
+        }
+        // Just move
+        else {
+            self.pos.y += y;
+        }
+
+        // Return value at this pos. Positions past the end of a short row
+        // are open ground.
+        let value = self.grid.get_wrapping_x(self.pos.x, self.pos.y as usize);
+        Ok(value.copied().unwrap_or(0))
+    }
+
+    /// Move to given position. Returns error if the position is outside
+    /// the map, position is not changed then.
+    pub fn set_position(&mut self, x: isize, y: isize) -> Result<()> {
+        if x < 0 || x >= self.width() as isize || y < 0 || y >= self.height() as isize {
+            bail!("Position ({}, {}) is outside the map", x, y)
+        }
+        self.pos.x = x;
+        self.pos.y = y;
+        Ok(())
+    }
+
+    pub fn reset_position(&mut self) {
+        self.pos.x = 0;
+        self.pos.y = 0;
+    }
+}
+
+/// Memory efficient alternative to TobogganMap for large maps. Each row
+/// is stored as a bitset where a set bit is a tree.
+pub struct PackedTobogganMap {
+    rows: Vec<Vec<u64>>,
+    width: usize,
+}
+
+impl PackedTobogganMap {
+    /// Create packed map instance from string representation of the map.
+    pub fn from_string_map_packed(mapstr: &str) -> PackedTobogganMap {
+        let mut rows = Vec::new();
+        let mut width = 0;
+
+        for row in mapstr.lines() {
+            let row = row.trim();
+            let cols = row.chars().count();
+            let mut bits = vec![0; cols.div_ceil(64)];
+            for (colnum, col) in row.chars().enumerate() {
+                if col == '#' {
+                    bits[colnum / 64] |= 1 << (colnum % 64);
+                }
+            }
+            width = width.max(cols);
+            rows.push(bits);
+        }
+
+        PackedTobogganMap { rows, width }
+    }
+
+    /// Number of rows on the map.
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Check whether there is a tree at (x, y). Map repeats infinitely
+    /// on x-axis. Positions below the map have no trees.
+    pub fn is_tree(&self, x: usize, y: usize) -> bool {
+        if self.width == 0 {
+            return false;
+        }
+        let x = x % self.width;
+        match self.rows.get(y).and_then(|row| row.get(x / 64)) {
+            Some(bits) => bits & (1 << (x % 64)) != 0,
+            None => false,
+        }
+    }
+}
+
+/// Count trees on packed map moving by (dx, dy) from the top-left
+/// corner until the end of map is reached. Slope that doesn't move down
+/// never reaches the end, so it counts no trees.
+pub fn count_trees_packed(map: &PackedTobogganMap, dx: usize, dy: usize) -> usize {
+    if dy == 0 {
+        return 0;
+    }
+    (1..)
+        .map(|step| (step * dx, step * dy))
+        .take_while(|(_, y)| *y < map.height())
+        .filter(|(x, y)| map.is_tree(*x, *y))
+        .count()
+}
+
+/// Move on the map by (dx, dy) until the end of map is reached and
+/// count the trees encountered on the way. Slope that doesn't move down
+/// never reaches the end, so it counts no trees.
+pub fn count_trees(map: &mut TobogganMap, dx: isize, dy: isize) -> usize {
+    if dy == 0 {
+        return 0;
+    }
+    let mut encountered_trees: usize = 0;
+    while let Ok(val) = map.move_by(dx, dy) {
+        encountered_trees += val;
+    }
+    encountered_trees
+}
+
+/// Count trees for every slope, starting each from the top-left corner.
+/// Returns the counts in the same order as the slopes and their product.
+/// Product of no slopes is 1.
+pub fn count_all_slopes(map: &mut TobogganMap, slopes: &[(isize, isize)]) -> (Vec<usize>, usize) {
+    let counts: Vec<usize> = slopes
+        .iter()
+        .map(|(dx, dy)| {
+            map.reset_position();
+            count_trees(map, *dx, *dy)
+        })
+        .collect();
+    let product = counts.iter().product();
+    (counts, product)
+}
+
+/// Count trees without building the map. Walks the lines directly and
+/// indexes the repeating row with column modulo row length. Slope that
+/// doesn't move down never reaches the end, so it counts no trees.
+pub fn count_trees_lazy(map: &str, dx: usize, dy: usize) -> usize {
+    if dy == 0 {
+        return 0;
+    }
+    map.lines()
+        .map(|row| row.trim())
+        .filter(|row| !row.is_empty())
+        .step_by(dy)
+        .enumerate()
+        .skip(1)
+        .filter(|(step, row)| row.chars().nth(step * dx % row.len()) == Some('#'))
+        .count()
+}
+
+/// Day 3 for the common Solver interface. Moving changes the position
+/// on the map, so each part travels on its own copy.
+pub struct Day03 {
+    map: TobogganMap,
+}
+
+impl Solver for Day03 {
+    fn parse(input: &str) -> Day03 {
+        Day03 {
+            map: TobogganMap::from_string_map(input),
+        }
+    }
+
+    fn part1(&self) -> Answer {
+        Answer::from(count_trees(&mut self.map.clone(), 3, 1))
+    }
+
+    fn part2(&self) -> Answer {
+        let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+        let (_, product) = count_all_slopes(&mut self.map.clone(), &slopes);
+        Answer::from(product)
+    }
+}
+
+#[cfg(test)]
+mod day_03 {
+    use super::*;
+
+    #[test]
+    fn count_trees_resets_between_slopes() {
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        assert_eq!(count_trees(&mut map, 1, 1), 2);
+        map.reset_position();
+        assert_eq!(count_trees(&mut map, 1, 2), 2);
+    }
+
+    #[test]
+    fn sample_slope_3_1() {
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        assert_eq!(count_trees(&mut map, 3, 1), 7);
+    }
+
+    #[test]
+    fn sample_product() {
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        let mut product = 1;
+        for (dx, dy) in [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter() {
+            product *= count_trees(&mut map, *dx, *dy);
+            map.reset_position();
+        }
+        assert_eq!(product, 336);
+    }
+
+    #[test]
+    fn all_slopes_breakdown() {
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+        let (counts, product) = count_all_slopes(&mut map, &slopes);
+        assert_eq!(counts, vec![2, 7, 3, 4, 2]);
+        assert_eq!(product, 336);
+        assert_eq!(count_all_slopes(&mut map, &[]), (vec![], 1));
+    }
+
+    #[test]
+    fn slope_without_trees() {
+        let mut map = TobogganMap::from_string_map(
+            "#..
+            ...
+            #..",
+        );
+        let (counts, product) = count_all_slopes(&mut map, &[(1, 1), (3, 1)]);
+        assert_eq!(counts, vec![0, 1]);
+        assert_eq!(product, 0);
+    }
+
+    #[test]
+    fn lazy_matches_map() {
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        assert_eq!(
+            count_trees_lazy(&get_input_test(), 3, 1),
+            count_trees(&mut map, 3, 1)
+        );
+        assert_eq!(count_trees_lazy(&get_input_test(), 1, 2), 2);
+    }
+
+    #[test]
+    fn horizontal_slope() {
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        assert_eq!(count_trees(&mut map, 3, 0), 0);
+        assert_eq!(count_trees_lazy(&get_input_test(), 3, 0), 0);
+        let packed = PackedTobogganMap::from_string_map_packed(&get_input_test());
+        assert_eq!(count_trees_packed(&packed, 3, 0), 0);
+    }
+
+    #[test]
+    fn packed_matches_map() {
+        let packed = PackedTobogganMap::from_string_map_packed(&get_input_test());
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        for (dx, dy) in [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter() {
+            map.reset_position();
+            assert_eq!(
+                count_trees_packed(&packed, *dx as usize, *dy as usize),
+                count_trees(&mut map, *dx, *dy)
+            );
+        }
+        assert!(packed.is_tree(2, 0));
+        assert!(packed.is_tree(13, 0));
+        assert!(!packed.is_tree(0, 11));
+    }
+
+    #[test]
+    fn packed_wide_map() {
+        let row = format!("{}#", ".".repeat(99));
+        let packed = PackedTobogganMap::from_string_map_packed(&format!("{}\n{}", row, row));
+        assert!(packed.is_tree(99, 1));
+        assert!(packed.is_tree(199, 0));
+        assert!(!packed.is_tree(64, 1));
+        assert_eq!(count_trees_packed(&packed, 99, 1), 1);
+    }
+
+    #[test]
+    fn start_mid_map() {
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        map.set_position(8, 5).unwrap();
+        // Row 6 is ".#.#.#....#" so column 8 + 2 is the tree at column 10.
+        assert_eq!(map.move_by(2, 1).unwrap(), 1);
+        // Row 7 is ".#........#" so column 10 + 2 wraps to the tree at column 1.
+        assert_eq!(map.move_by(2, 1).unwrap(), 1);
+        assert_eq!((map.pos.x, map.pos.y), (1, 7));
+
+        map.set_position(5, 10).unwrap();
+        assert!(map.move_by(1, 1).is_err());
+    }
+
+    #[test]
+    fn start_outside_map() {
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        map.set_position(3, 3).unwrap();
+        for (x, y) in [(-1, 0), (11, 0), (0, -1), (0, 11)].iter() {
+            let e = map.set_position(*x, *y).unwrap_err();
+            assert_eq!(
+                e.to_string(),
+                format!("Position ({}, {}) is outside the map", x, y)
+            );
+        }
+        assert_eq!((map.pos.x, map.pos.y), (3, 3));
+    }
+
+    #[test]
+    fn map_dimensions() {
+        let map = TobogganMap::from_string_map(&get_input_test());
+        assert_eq!(map.width(), 11);
+        assert_eq!(map.height(), 11);
+        assert_eq!(map.grid.rows().count(), 11);
+        assert!(map.grid.rows().all(|row| row.len() == 11));
+        assert_eq!(
+            map.grid.cells().filter(|(_, _, tree)| **tree == 1).count(),
+            37
+        );
+    }
+
+    #[test]
+    fn horizontal_wrap() {
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        // Row 1 is "#...#...#.." so column 11 wraps to the tree at column 0.
+        assert_eq!(map.move_by(11, 1).unwrap(), 1);
+        assert_eq!(map.pos.x, 0);
+        // Row 2 is ".#....#..#." so column 9 + 3 wraps to the tree at column 1.
+        map.pos.x = 9;
+        assert_eq!(map.move_by(3, 1).unwrap(), 1);
+        assert_eq!(map.pos.x, 1);
+    }
+
+    #[test]
+    fn wrap_more_than_map_width() {
+        let mut map = TobogganMap::from_string_map(
+            "..#
+            #..
+            .#.",
+        );
+        // 3 wide map, moving 7 right lands on column 1.
+        assert_eq!(map.move_by(7, 1).unwrap(), 0);
+        assert_eq!(map.pos.x, 1);
+        assert_eq!(map.move_by(9, 1).unwrap(), 1);
+        assert_eq!(map.pos.x, 1);
+    }
+
+    #[test]
+    fn left_wrap() {
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        // Row 1 is "#...#...#.." so column 0 - 1 wraps to the last column 10.
+        assert_eq!(map.move_by(-1, 1).unwrap(), 0);
+        assert_eq!((map.pos.x, map.pos.y), (10, 1));
+        // Row 2 is ".#....#..#." so column 10 - 1 is the tree at column 9.
+        assert_eq!(map.move_by(-1, 1).unwrap(), 1);
+        assert_eq!((map.pos.x, map.pos.y), (9, 2));
+        // Row 3 is "..#.#...#.#" so column 9 - 12 wraps to the tree at column 8.
+        assert_eq!(map.move_by(-12, 1).unwrap(), 1);
+        assert_eq!((map.pos.x, map.pos.y), (8, 3));
+    }
+
+    #[test]
+    fn upward_move() {
+        let mut map = TobogganMap::from_string_map(&get_input_test());
+        // Moving up is allowed inside the map, row 2 column 0 is open.
+        map.set_position(0, 3).unwrap();
+        assert_eq!(map.move_by(0, -1).unwrap(), 0);
+        assert_eq!((map.pos.x, map.pos.y), (0, 2));
+
+        // Moving up from the top row is not.
+        map.reset_position();
+        let e = map.move_by(0, -1).unwrap_err();
+        assert_eq!(e.to_string(), "Illegal move");
+        assert_eq!((map.pos.x, map.pos.y), (0, 0));
+    }
+
+    #[test]
+    fn custom_tree_glyph() {
+        let input = get_input_test().replace('#', "@");
+        let mut map = TobogganMap::from_string_map_with(&input, '@');
+        assert_eq!(count_trees(&mut map, 3, 1), 7);
+        let mut map = TobogganMap::from_string_map(&input);
+        assert_eq!(count_trees(&mut map, 3, 1), 0);
+    }
+
+    #[test]
+    fn checked_map() {
+        let map = TobogganMap::from_string_map_checked(&get_input_test()).unwrap();
+        assert_eq!((map.width(), map.height()), (11, 11));
+
+        let e = TobogganMap::from_string_map_checked("..#\n#.\n.#.")
+            .err()
+            .unwrap();
+        assert_eq!(e.to_string(), "Row 1 is 2 wide, expected 3");
+        let e = TobogganMap::from_string_map_checked("").err().unwrap();
+        assert_eq!(e.to_string(), "Map is empty");
+    }
+}
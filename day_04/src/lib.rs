@@ -0,0 +1,784 @@
+/*!
+# Advent of Code 2020 - Day 04
+[Link to task.](https://adventofcode.com/2020/day/4)
+
+Detect which passports are valid eq. have all required
+fields with some limitations.
+
+Passport data is validated in batch files (your puzzle input).
+Each passport is represented as a sequence of key:value pairs
+separated by spaces or newlines. Passports are separated by blank
+lines.
+
+Only "cid" is allowed to be missing from otherwise valid passport.
+All other fields are required.
+
+Part 1 only checks that the required fields are present. In part 2
+fields have to validated by these rules:
+    byr (Birth Year) - four digits; at least 1920 and at most 2002.
+    iyr (Issue Year) - four digits; at least 2010 and at most 2020.
+    eyr (Expiration Year) - four digits; at least 2020 and at most 2030.
+    hgt (Height) - a number followed by either cm or in:
+        If cm, the number must be at least 150 and at most 193.
+        If in, the number must be at least 59 and at most 76.
+    hcl (Hair Color) - a # followed by exactly six characters 0-9 or a-f.
+    ecl (Eye Color) - exactly one of: amb blu brn gry grn hzl oth.
+    pid (Passport ID) - a nine-digit number, including leading zeroes.
+    cid (Country ID) - ignored, missing or not.
+
+## Usage example
+
+```text ignore
+PS> cargo run --bin day_04
+    Finished dev [unoptimized + debuginfo] target(s) in 0.16s
+     Running `target\debug\day_04.exe`
+Advent of Code 2020 - Day 04
+Info: Using hard-coded test data. ".aoc-session" not found.
+Part 1 answer: 2 passports with required fields.
+Part 2 answer: 2 valid passports.
+2 of 4 passports valid (50%).
+```
+!*/
+
+use anyhow::{bail, Result};
+use aoc_common::{Answer, InputSource, Options, Solver};
+use std::collections::HashMap;
+use std::io::BufRead;
+
+static AOC_DAY: u32 = 4;
+
+/// If input data download was not available, this function
+/// returns hardcoded test data which is allowed to be shared.
+pub fn get_input_test() -> String {
+    String::from(
+        "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+        byr:1937 iyr:2017 cid:147 hgt:183cm
+        
+        iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+        hcl:#cfa07d byr:1929
+        
+        hcl:#ae17e1 iyr:2013
+        eyr:2024
+        ecl:brn pid:760753108 byr:1931
+        hgt:179cm
+        
+        hcl:#cfa07d eyr:2025 pid:166559648
+        iyr:2011 ecl:brn hgt:59in",
+    )
+    .to_owned()
+}
+
+/// Get input data either from AOC website or fall-back to local
+/// hard-coded test data.
+pub fn get_input(opts: &Options) -> Result<(String, InputSource)> {
+    aoc_common::input_or_fallback(AOC_DAY, opts, get_input_test)
+}
+
+#[derive(Default)]
+struct F32Unit {
+    value: f32,
+    unit: Option<String>,
+}
+
+impl std::str::FromStr for F32Unit {
+    type Err = std::num::ParseFloatError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let split: (&str, &str) = match input.find(|s: char| s.is_alphabetic()) {
+            Some(splitpoint) => input.split_at(splitpoint),
+            None => (input, ""),
+        };
+        let value: f32 = split.0.parse()?;
+        let unit = split.1;
+
+        if split.1.is_empty() {
+            Ok(F32Unit { value, unit: None })
+        } else {
+            Ok(F32Unit {
+                value,
+                unit: Some(unit.into()),
+            })
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Passport {
+    birth_year: usize,
+    issue_year: usize,
+    expiration_year: usize,
+    height: F32Unit,
+    hair_color: String,
+    eye_color: String,
+    passport_id: String,
+    country_id: Option<String>,
+}
+
+/// Validates the value of one field and stores it to the passport.
+/// Arguments are the passport, the key and the value.
+pub type FieldSetter = fn(&mut Passport, &str, &str) -> Result<()>;
+
+/// Required fields in the order they are validated. Adding or removing
+/// a required field is a change of one line here.
+static REQUIRED_FIELDS: [(&str, FieldSetter); 7] = [
+    ("byr", |p, k, v| {
+        p.birth_year = Passport::validate_year(k, v, 1920, 2002)?;
+        Ok(())
+    }),
+    ("iyr", |p, k, v| {
+        p.issue_year = Passport::validate_year(k, v, 2010, 2020)?;
+        Ok(())
+    }),
+    ("eyr", |p, k, v| {
+        p.expiration_year = Passport::validate_year(k, v, 2020, 2030)?;
+        Ok(())
+    }),
+    ("hgt", |p, k, v| {
+        p.height = Passport::validate_height(k, v, (150.0, 193.0), (59.0, 76.0))?;
+        Ok(())
+    }),
+    ("hcl", |p, k, v| {
+        p.hair_color = Passport::validate_haircolor(k, v)?;
+        Ok(())
+    }),
+    ("ecl", |p, k, v| {
+        p.eye_color = Passport::validate_eyecolor(k, v)?;
+        Ok(())
+    }),
+    ("pid", |p, k, v| {
+        p.passport_id = Passport::validate_id(k, v)?;
+        Ok(())
+    }),
+];
+
+/// Optional fields, which are stored if present.
+static OPTIONAL_FIELDS: [(&str, FieldSetter); 1] = [("cid", |p, _, v| {
+    p.country_id = Some(v.to_string());
+    Ok(())
+})];
+
+/// Which fields a passport must have, which it may have, and how each
+/// of them is validated. Default is the rules of Advent of Code 2020,
+/// where every field except "cid" is required.
+pub struct FieldPolicy {
+    required: Vec<(&'static str, FieldSetter)>,
+    optional: Vec<(&'static str, FieldSetter)>,
+}
+
+impl Default for FieldPolicy {
+    fn default() -> FieldPolicy {
+        FieldPolicy {
+            required: REQUIRED_FIELDS.to_vec(),
+            optional: OPTIONAL_FIELDS.to_vec(),
+        }
+    }
+}
+
+impl FieldPolicy {
+    /// Policy without any fields.
+    pub fn empty() -> FieldPolicy {
+        FieldPolicy {
+            required: Vec::new(),
+            optional: Vec::new(),
+        }
+    }
+
+    /// Add a required field, replacing any earlier rule for the key.
+    pub fn require(mut self, key: &'static str, setter: FieldSetter) -> FieldPolicy {
+        self.remove(key);
+        self.required.push((key, setter));
+        self
+    }
+
+    /// Add an optional field, replacing any earlier rule for the key.
+    pub fn allow(mut self, key: &'static str, setter: FieldSetter) -> FieldPolicy {
+        self.remove(key);
+        self.optional.push((key, setter));
+        self
+    }
+
+    /// Make a known optional field required, keeping its validation.
+    pub fn make_required(mut self, key: &str) -> FieldPolicy {
+        if let Some(pos) = self.optional.iter().position(|(k, _)| *k == key) {
+            let field = self.optional.remove(pos);
+            self.required.push(field);
+        }
+        self
+    }
+
+    /// Is the key either a required or an optional field.
+    pub fn is_known(&self, key: &str) -> bool {
+        self.required
+            .iter()
+            .chain(self.optional.iter())
+            .any(|(k, _)| *k == key)
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.required.retain(|(k, _)| *k != key);
+        self.optional.retain(|(k, _)| *k != key);
+    }
+}
+
+impl Passport {
+    // Parse the input string into Passport instance.
+    // Input key:value pairs are parsed to a hashmap
+    // where the data is used to construct Passport.
+    // The data is validated on construction, field by field
+    // in the order of REQUIRED_FIELDS.
+    pub fn from_string(input: &str) -> Result<Passport> {
+        Passport::from_string_with_policy(input, &FieldPolicy::default())
+    }
+
+    /// Parse passport using the given rules instead of the Advent of
+    /// Code ones. Required fields are validated first, in the order they
+    /// were added to the policy.
+    pub fn from_string_with_policy(input: &str, policy: &FieldPolicy) -> Result<Passport> {
+        let fields = Passport::str_to_hashmap(input);
+        let mut passport = Passport::default();
+
+        for (key, set_field) in policy.required.iter() {
+            match fields.get(key) {
+                Some(value) => set_field(&mut passport, key, value)?,
+                None => bail!("Missing field {}.", key),
+            }
+        }
+        for (key, set_field) in policy.optional.iter() {
+            if let Some(value) = fields.get(key) {
+                set_field(&mut passport, key, value)?;
+            }
+        }
+
+        Ok(passport)
+    }
+
+    /// Strict variant of from_string. Also rejects passports with keys
+    /// that are not required or optional fields, like a typo'd "byrr".
+    pub fn from_string_strict(input: &str) -> Result<Passport> {
+        let policy = FieldPolicy::default();
+        for key in Passport::str_to_hashmap(input).keys() {
+            if !policy.is_known(key) {
+                bail!("Unknown field {}.", key);
+            }
+        }
+        Passport::from_string_with_policy(input, &policy)
+    }
+
+    /// Short description for listing passports: birth year, eye color
+    /// and passport ID, like "byr:1937 ecl:gry pid:860033327".
+    pub fn summary(&self) -> String {
+        format!(
+            "byr:{} ecl:{} pid:{}",
+            self.birth_year, self.eye_color, self.passport_id
+        )
+    }
+
+    /// Birth year (byr).
+    pub fn birth_year(&self) -> usize {
+        self.birth_year
+    }
+
+    /// Issue year (iyr).
+    pub fn issue_year(&self) -> usize {
+        self.issue_year
+    }
+
+    /// Expiration year (eyr).
+    pub fn expiration_year(&self) -> usize {
+        self.expiration_year
+    }
+
+    /// Height (hgt) in centimeters. Heights given in inches are converted.
+    pub fn height_in_cm(&self) -> f32 {
+        match self.height.unit.as_deref() {
+            Some("in") => self.height.value * 2.54,
+            _ => self.height.value,
+        }
+    }
+
+    /// Hair color (hcl), for example "#fffffd".
+    pub fn hair_color(&self) -> &str {
+        &self.hair_color
+    }
+
+    /// Eye color (ecl), for example "gry".
+    pub fn eye_color(&self) -> &str {
+        &self.eye_color
+    }
+
+    /// Passport ID (pid), nine digits.
+    pub fn passport_id(&self) -> &str {
+        &self.passport_id
+    }
+
+    /// Country ID (cid) if present.
+    pub fn country_id(&self) -> Option<&str> {
+        self.country_id.as_deref()
+    }
+
+    /// Get hashmap of key:value pairs from one passport block. Tokens
+    /// that are not exactly one key:value pair are skipped. Values are
+    /// not validated, so custom rules can be built on top of this.
+    pub fn str_to_hashmap(input: &str) -> HashMap<&str, &str> {
+        let mut fields: HashMap<&str, &str> = HashMap::new();
+        for item in input.split_whitespace() {
+            // Skip tokens that are not exactly one key:value pair.
+            if let Some((k, v)) = item.split_once(':') {
+                if !v.contains(':') {
+                    let _ = fields.insert(k, v);
+                }
+            }
+        }
+        fields
+    }
+
+    // Validate year to between low and high. Years must be exactly four
+    // digits, so "+1980" or "02002" are not accepted as numbers.
+    fn validate_year(k: &str, v: &str, low: usize, high: usize) -> Result<usize> {
+        if v.len() != 4 || !v.bytes().all(|b| b.is_ascii_digit()) {
+            bail!("Invalid: {} - not four digits", k);
+        }
+        Passport::validate_number(k, v, low, high)
+    }
+
+    // Validate data to between low and high. If not valid, return Err early.
+    fn validate_number(k: &str, v: &str, low: usize, high: usize) -> Result<usize> {
+        match aoc_common::parse_in_range(v, low, high) {
+            Ok(v) => Ok(v),
+            Err(e) => bail!("Invalid: {} - {}", k, e),
+        }
+    }
+
+    // Validate data to between cm_low and cm_high if unit is cm.
+    // Validate data to between in_low and in_high if unit is in.
+    // If not valid, return Err early.
+    fn validate_height(
+        k: &str,
+        v: &str,
+        (cm_low, cm_high): (f32, f32),
+        (in_low, in_high): (f32, f32),
+    ) -> Result<F32Unit> {
+        // Parse the value to correct type
+        let v = match v.parse::<F32Unit>() {
+            Ok(v) => v,
+            Err(_) => bail!("Malformed field {}.", k),
+        };
+
+        // Heights are whole numbers.
+        if v.value.fract() != 0.0 {
+            bail!("Invalid: {} - not a whole number", k);
+        }
+
+        match &v.unit {
+            Some(unit) => {
+                if unit == "cm" {
+                    if v.value < cm_low {
+                        bail!("Invalid: {} < {} cm", k, cm_low);
+                    };
+                    if v.value > cm_high {
+                        bail!("Invalid: {} < {} cm", k, cm_high);
+                    };
+                } else if unit == "in" {
+                    if v.value < in_low {
+                        bail!("Invalid: {} < {} inch", k, in_low);
+                    };
+                    if v.value > in_high {
+                        bail!("Invalid: {} < {} inch", k, in_high);
+                    };
+                } else {
+                    bail!("Invalid: {} - unknown unit", k);
+                }
+            }
+            None => bail!("Invalid: {} - no unit", k),
+        }
+
+        Ok(v)
+    }
+
+    // Validate data to # followed by exactly six characters 0-9 or a-f.
+    // If not valid, return Err early.
+    fn validate_haircolor(k: &str, v: &str) -> Result<String> {
+        // '#' followed by six lower case hex digits.
+        let valid = v.len() == 7
+            && v.starts_with('#')
+            && v.bytes()
+                .skip(1)
+                .all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'));
+
+        if valid {
+            Ok(v.to_owned())
+        } else {
+            bail!("Invalid: {}", k);
+        }
+    }
+
+    // Validate data to exactly one of: amb blu brn gry grn hzl oth.
+    // If not valid, return Err early.
+    fn validate_eyecolor(k: &str, v: &str) -> Result<String> {
+        if ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&v) {
+            Ok(v.to_owned())
+        } else {
+            bail!("Invalid: {}", k);
+        }
+    }
+
+    // Validate data to exactly nine digits, including leading zeroes.
+    // If not valid, return Err early.
+    fn validate_id(k: &str, v: &str) -> Result<String> {
+        if v.len() == 9 && v.bytes().all(|b| b.is_ascii_digit()) {
+            Ok(v.to_owned())
+        } else {
+            bail!("Invalid: {}", k);
+        }
+    }
+}
+
+/// Count passports that have all required fields present. Values of
+/// the fields are not validated.
+pub fn count_present_passports(input: &str) -> usize {
+    aoc_common::split_blocks(input)
+        .into_iter()
+        .map(Passport::str_to_hashmap)
+        .filter(|fields| {
+            ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]
+                .iter()
+                .all(|key| fields.contains_key(key))
+        })
+        .count()
+}
+
+/// Validate every passport in input and keep the result of each, so
+/// the reason why a passport was rejected is not lost.
+pub fn validate_passports_verbose(input: &str) -> Vec<Result<Passport>> {
+    aoc_common::split_blocks(input)
+        .into_iter()
+        .map(Passport::from_string)
+        .collect()
+}
+
+/// Count valid passports and all passports in input. Returns (valid,
+/// total) where total includes every non-empty block, also the ones
+/// that fail validation.
+pub fn validation_stats(input: &str) -> (usize, usize) {
+    validate_passports_verbose(input)
+        .iter()
+        .fold((0, 0), |(valid, total), result| {
+            (valid + result.is_ok() as usize, total + 1)
+        })
+}
+
+/// Validate passports read from `reader` one block at a time, so large
+/// batch files don't have to fit in memory. Empty blocks are skipped.
+/// Reading stops after the first I/O error, which is returned as the
+/// last item.
+pub fn parse_passports_from_reader<R: BufRead>(
+    reader: R,
+) -> impl Iterator<Item = Result<Passport>> {
+    let mut lines = reader.lines();
+    let mut failed = false;
+
+    std::iter::from_fn(move || {
+        let mut block = String::new();
+        while !failed {
+            match lines.next() {
+                Some(Ok(line)) if line.trim().is_empty() => {
+                    if !block.is_empty() {
+                        break;
+                    }
+                }
+                Some(Ok(line)) => {
+                    block.push_str(&line);
+                    block.push('\n');
+                }
+                Some(Err(e)) => {
+                    failed = true;
+                    return Some(Err(e.into()));
+                }
+                None => break,
+            }
+        }
+
+        if block.is_empty() {
+            None
+        } else {
+            Some(Passport::from_string(&block))
+        }
+    })
+}
+
+pub fn parse_string_to_passports(input: &str) -> Vec<Passport> {
+    let mut output: Vec<Passport> = Vec::new();
+
+    // Parse input data and pass blocks of str to Passport constructor
+    // if Passport returns valid passport, add it to the vec.
+    for block in aoc_common::split_blocks(input) {
+        if let Ok(passport) = Passport::from_string(block) {
+            output.push(passport);
+        }
+    }
+
+    output
+}
+
+/// Day 4 for the common Solver interface. Both parts validate the same
+/// passport blocks, so the input is kept as it is.
+pub struct Day04 {
+    input: String,
+}
+
+impl Solver for Day04 {
+    fn parse(input: &str) -> Day04 {
+        Day04 {
+            input: input.to_string(),
+        }
+    }
+
+    fn part1(&self) -> Answer {
+        Answer::from(count_present_passports(&self.input))
+    }
+
+    fn part2(&self) -> Answer {
+        Answer::from(parse_string_to_passports(&self.input).len())
+    }
+}
+
+#[cfg(test)]
+mod day_04 {
+    use super::*;
+
+    #[test]
+    fn present_passports_sample() {
+        assert_eq!(count_present_passports(&get_input_test()), 2);
+    }
+
+    #[test]
+    fn stats_sample() {
+        assert_eq!(validation_stats(&get_input_test()), (2, 4));
+        assert_eq!(validation_stats("\n\n"), (0, 0));
+    }
+
+    #[test]
+    fn verbose_keeps_errors() {
+        let results = validate_passports_verbose(&get_input_test());
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok());
+        assert!(results[2].is_ok());
+        let e = results[1].as_ref().err().unwrap();
+        assert_eq!(e.to_string(), "Missing field hgt.");
+    }
+
+    #[test]
+    fn passports_from_reader() {
+        let reader = std::io::Cursor::new(get_input_test().into_bytes());
+        let results: Vec<Result<Passport>> = parse_passports_from_reader(reader).collect();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 2);
+        assert_eq!(
+            results[1].as_ref().err().unwrap().to_string(),
+            "Missing field hgt."
+        );
+    }
+
+    fn passport_with_height(hgt: &str) -> Result<Passport> {
+        Passport::from_string(&format!(
+            "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd byr:1937 iyr:2017 hgt:{}",
+            hgt
+        ))
+    }
+
+    #[test]
+    fn height_whole_number() {
+        assert!(passport_with_height("170cm").is_ok());
+    }
+
+    #[test]
+    fn height_fractional() {
+        let e = passport_with_height("170.5cm").err().unwrap();
+        assert_eq!(e.to_string(), "Invalid: hgt - not a whole number");
+    }
+
+    #[test]
+    fn height_malformed() {
+        let e = passport_with_height("abccm").err().unwrap();
+        assert_eq!(e.to_string(), "Malformed field hgt.");
+    }
+
+    #[test]
+    fn height_without_number() {
+        for hgt in &["cm", ""] {
+            let e = passport_with_height(hgt).err().unwrap();
+            assert_eq!(e.to_string(), "Malformed field hgt.");
+        }
+    }
+
+    #[test]
+    fn valid_passport_summaries() {
+        let summaries: Vec<String> = parse_string_to_passports(&get_input_test())
+            .iter()
+            .map(|p| p.summary())
+            .collect();
+        assert_eq!(
+            summaries,
+            vec![
+                "byr:1937 ecl:gry pid:860033327",
+                "byr:1931 ecl:brn pid:760753108"
+            ]
+        );
+    }
+
+    #[test]
+    fn passport_accessors() {
+        let passport = Passport::from_string(
+            "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 hcl:#623a2f cid:88",
+        )
+        .unwrap();
+        assert_eq!(passport.birth_year(), 1980);
+        assert_eq!(passport.eye_color(), "grn");
+        assert_eq!(passport.hair_color(), "#623a2f");
+        assert_eq!(passport.passport_id(), "087499704");
+        assert_eq!(passport.country_id(), Some("88"));
+        assert!((passport.height_in_cm() - 187.96).abs() < 0.01);
+    }
+
+    #[test]
+    fn height_in_inches_as_cm() {
+        let passport = passport_with_height("59in").unwrap();
+        assert!((passport.height_in_cm() - 149.86).abs() < 0.01);
+        let passport = passport_with_height("150cm").unwrap();
+        assert_eq!(passport.height_in_cm(), 150.0);
+    }
+
+    #[test]
+    fn missing_field_by_name() {
+        let input = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd byr:1937 iyr:2017 hgt:183cm";
+        assert!(Passport::from_string(input).is_ok());
+        for (key, _) in REQUIRED_FIELDS.iter() {
+            let block: Vec<&str> = input
+                .split_whitespace()
+                .filter(|field| !field.starts_with(key))
+                .collect();
+            let e = Passport::from_string(&block.join(" ")).err().unwrap();
+            assert_eq!(e.to_string(), format!("Missing field {}.", key));
+        }
+    }
+
+    #[test]
+    fn unknown_field_strict() {
+        let input =
+            "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd byr:1937 byrr:1980 iyr:2017 hgt:183cm";
+        assert!(Passport::from_string(input).is_ok());
+        let e = Passport::from_string_strict(input).err().unwrap();
+        assert_eq!(e.to_string(), "Unknown field byrr.");
+        assert!(Passport::from_string_strict(&format!(
+            "{} cid:88",
+            input.replace("byrr:1980 ", "")
+        ))
+        .is_ok());
+    }
+
+    #[test]
+    fn year_out_of_range() {
+        let input = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd iyr:2017 hgt:183cm";
+        let e = Passport::from_string(&format!("{} byr:1919", input))
+            .err()
+            .unwrap();
+        assert_eq!(e.to_string(), "Invalid: byr - 1919 is below 1920");
+        let e = Passport::from_string(&format!("{} byr:abc", input))
+            .err()
+            .unwrap();
+        assert_eq!(e.to_string(), "Invalid: byr - not four digits");
+    }
+
+    #[test]
+    fn year_not_four_digits() {
+        let input = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd iyr:2017 hgt:183cm";
+        for year in &["02002", "+1980", "198"] {
+            let e = Passport::from_string(&format!("{} byr:{}", input, year))
+                .err()
+                .unwrap();
+            assert_eq!(e.to_string(), "Invalid: byr - not four digits");
+        }
+        assert!(Passport::from_string(&format!("{} byr:1980", input)).is_ok());
+    }
+
+    #[test]
+    fn custom_policy_requires_cid() {
+        let input = "hcl:#ae17e1 iyr:2013 eyr:2024 ecl:brn pid:760753108 byr:1931 hgt:179cm";
+        assert!(Passport::from_string(input).is_ok());
+        let policy = FieldPolicy::default().make_required("cid");
+        let e = Passport::from_string_with_policy(input, &policy)
+            .err()
+            .unwrap();
+        assert_eq!(e.to_string(), "Missing field cid.");
+        let passport = Passport::from_string_with_policy(&format!("{} cid:88", input), &policy);
+        assert_eq!(passport.unwrap().country_id(), Some("88"));
+    }
+
+    #[test]
+    fn custom_policy_fields() {
+        let policy = FieldPolicy::empty()
+            .require("pid", |_, k, v| match v.len() {
+                4 => Ok(()),
+                _ => bail!("Invalid: {}", k),
+            })
+            .allow("byr", |_, _, _| Ok(()));
+        assert!(policy.is_known("byr"));
+        assert!(!policy.is_known("cid"));
+        assert!(Passport::from_string_with_policy("pid:1234", &policy).is_ok());
+        let e = Passport::from_string_with_policy("pid:12345", &policy)
+            .err()
+            .unwrap();
+        assert_eq!(e.to_string(), "Invalid: pid");
+    }
+
+    #[test]
+    fn passport_id_length() {
+        let input = "ecl:gry eyr:2020 hcl:#fffffd byr:1937 iyr:2017 hgt:183cm";
+        assert!(Passport::from_string(&format!("{} pid:012345678", input)).is_ok());
+        let e = Passport::from_string(&format!("{} pid:0123456789", input))
+            .err()
+            .unwrap();
+        assert_eq!(e.to_string(), "Invalid: pid");
+    }
+
+    #[test]
+    fn hair_color_hex() {
+        let input = "ecl:gry pid:860033327 eyr:2020 byr:1937 iyr:2017 hgt:183cm";
+        assert!(Passport::from_string(&format!("{} hcl:#123abc", input)).is_ok());
+        for hcl in &["#12345g", "123abc", "#123abcd", "#123ABC"] {
+            let e = Passport::from_string(&format!("{} hcl:{}", input, hcl))
+                .err()
+                .unwrap();
+            assert_eq!(e.to_string(), "Invalid: hcl");
+        }
+    }
+
+    #[test]
+    fn raw_fields() {
+        let fields = Passport::str_to_hashmap(
+            "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd\nbyr:1937 iyr:2017 cid:147 hgt:183cm",
+        );
+        assert_eq!(fields.len(), 8);
+        for key in &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"] {
+            assert!(fields.contains_key(key));
+        }
+        assert_eq!(fields["hgt"], "183cm");
+    }
+
+    #[test]
+    fn malformed_tokens_are_skipped() {
+        let passport = Passport::from_string(
+            "ecl:gry foo pid:860033327 eyr:2020 a:b:c hcl:#fffffd byr:1937 iyr:2017 hgt:183cm",
+        );
+        assert!(passport.is_ok());
+        let fields = Passport::str_to_hashmap("foo a:b:c byr:1937");
+        assert_eq!(fields.len(), 1);
+    }
+
+    #[test]
+    fn many_passports() {
+        let input = vec![get_input_test(); 2000].join("\n\n");
+        let passports = parse_string_to_passports(&input);
+        assert_eq!(passports.len(), 2 * 2000);
+    }
+}
@@ -0,0 +1,447 @@
+/*!
+# Advent of Code 2020 - Day 05
+[Link to task.](https://adventofcode.com/2020/day/5)
+
+What is the ID of your seat? Your seat wasn't at the very
+front or back, though; the seats with IDs +1 and -1 from
+yours will be in your list.
+
+The seat IDs are written in binary space partition using
+F, B, L & R letters. First 7 letters are either Front or
+Back, where Front means lower half. These specify exactly
+one of 0..127 possible rows. Last three characters are either
+Left or Right, where Left means lower half. These specify
+exactly one of 0..7 possible seats.
+
+Seat ID is calculated by multiplying row by 8 and add column.
+
+## Usage example
+
+```text ignore
+PS> cargo run --bin day_05 -- --input seats.txt
+    Finished dev [unoptimized + debuginfo] target(s) in 1.77s
+     Running `target\debug\day_05.exe --input seats.txt`
+Advent of Code 2020 - Day 05
+Info: Reading input data from: seats.txt
+Part 1 answer: 530 is the highest seat ID.
+Part 2 answer: PlaneSeat { row: 65, seat: 4, id: 524 } is my seat!
+```
+
+## Notes
+
+I wanted to try bitmasks and bit manipulations as a solution
+for this binary space partitioning task.
+!*/
+
+use anyhow::{bail, Result};
+use aoc_common::{Answer, InputSource, Options, Solver};
+use log::warn;
+use std::collections::{BTreeSet, HashSet};
+
+static AOC_DAY: u32 = 5;
+static MAX_ROW: usize = 127;
+static MAX_SEAT: usize = 7;
+
+/// If input data download was not available, this function
+/// returns hardcoded test data which is allowed to be shared.
+pub fn get_input_test() -> String {
+    String::from(
+        "FBFBBFFRLR
+        BFFFBBFRRR
+        FFFBBBFRRR
+        BBFFBBFRLL",
+    )
+    .to_owned()
+}
+
+/// Get input data either from AOC website or fall-back to local
+/// hard-coded test data.
+pub fn get_input(opts: &Options) -> Result<(String, InputSource)> {
+    aoc_common::input_or_fallback(AOC_DAY, opts, get_input_test)
+}
+
+/// Seats are ordered by row and then by seat, which is the same as
+/// ordering by id.
+#[derive(Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PlaneSeat {
+    row: usize,
+    seat: usize,
+    id: usize,
+}
+
+impl PlaneSeat {
+    /// Create seat at given row and seat. Returns None if either is
+    /// outside the plane.
+    pub fn new(row: usize, seat: usize) -> Option<PlaneSeat> {
+        Some(PlaneSeat {
+            row,
+            seat,
+            id: get_seat_id(row, seat)?,
+        })
+    }
+
+    /// Create seat from its id, row is id / 8 and seat id % 8. Returns
+    /// None if id is outside the plane.
+    pub fn from_id(id: usize) -> Option<PlaneSeat> {
+        PlaneSeat::new(id / 8, id % 8)
+    }
+
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    pub fn seat(&self) -> usize {
+        self.seat
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+}
+
+/// Seat id is row * 8 + seat. Returns None if row is over 127 or seat
+/// over 7, so valid ids are 0 - 1023.
+pub fn get_seat_id(row: usize, seat: usize) -> Option<usize> {
+    if row > MAX_ROW || seat > MAX_SEAT {
+        return None;
+    }
+    Some(row * 8 + seat)
+}
+
+/// Decode seat from boarding pass. Surrounding whitespace, like "\r" of
+/// Windows line endings, is ignored but whitespace inside is not.
+pub fn string_to_planeseat(input: &str) -> Option<PlaneSeat> {
+    let input = input.trim();
+    if input.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let mut row_mask: u8 = 0b1111111; // 127 is the highest possible row
+    let mut seat_mask: u8 = 0b111; // 7 is the highest possible seat
+
+    // We step the row_mask from left to right.
+    // If we are keeping the lower value, we set mask at that index to 0.
+    // Otherwise we leave the mask at 1.
+    // Binary masks are complex. Here be dragons.
+    // A letter in the wrong half of the pass is as invalid as any other
+    // character, so the shifts below never underflow.
+    for c in input.chars().enumerate() {
+        match c {
+            (0..=6, 'F') => row_mask &= 0b1111111 ^ 1 << (6 - c.0), // offset 0 - 6, Set to zero
+            (0..=6, 'B') => row_mask |= 1 << (6 - c.0),             // offset 0 - 6, Set to one
+            (7..=9, 'L') => seat_mask &= 0b0000111 ^ 1 << (2 - (c.0 - 7)), // offset 7 - 9, Set to zero
+            (7..=9, 'R') => seat_mask |= 1 << (2 - (c.0 - 7)), // offset 7 - 9, Set to one
+            _ => break,
+        }
+
+        if c.0 == 9 {
+            return PlaneSeat::new(row_mask as usize, seat_mask as usize);
+        }
+        if c.0 > 9 {
+            // Something is wrong
+            return None;
+        }
+    }
+
+    None
+}
+
+/// Alternative to string_to_planeseat. F and L are translated to 0,
+/// B and R to 1, and the resulting 10-bit binary string is the seat id.
+/// Row is the upper seven bits and seat the lower three bits.
+pub fn decode_seat(input: &str) -> Option<PlaneSeat> {
+    if input.len() != 10 {
+        return None;
+    }
+
+    let mut binary = String::with_capacity(10);
+    for (i, c) in input.chars().enumerate() {
+        binary.push(match (i < 7, c) {
+            (true, 'F') | (false, 'L') => '0',
+            (true, 'B') | (false, 'R') => '1',
+            _ => return None,
+        });
+    }
+
+    let id = u16::from_str_radix(&binary, 2).ok()? as usize;
+    PlaneSeat::new(id >> 3, id & 0b111)
+}
+
+/// Strict variant of decode_seat. Returns an error telling whether the
+/// input is too short, too long or has an invalid character.
+pub fn try_decode_seat(input: &str) -> Result<PlaneSeat> {
+    let mut length = 0;
+    for (i, c) in input.chars().enumerate() {
+        if i >= 10 {
+            bail!("Seat {:?} is too long.", input);
+        }
+        let valid = match i {
+            0..=6 => c == 'F' || c == 'B',
+            _ => c == 'L' || c == 'R',
+        };
+        if !valid {
+            bail!("Invalid character {:?} at position {}.", c, i);
+        }
+        length += 1;
+    }
+    if length < 10 {
+        bail!("Seat {:?} is too short.", input);
+    }
+
+    Ok(decode_seat(input).unwrap())
+}
+
+/// Get PlaneSeats from input data. Lines that can't be parsed, like
+/// trailing blank lines, are skipped.
+pub fn parse_seats(input: &str) -> Vec<PlaneSeat> {
+    input.lines().filter_map(string_to_planeseat).collect()
+}
+
+/// Get ids of every seat in input data sorted ascending. Lines that
+/// can't be parsed are skipped.
+pub fn seat_ids(input: &str) -> Vec<usize> {
+    let mut ids: Vec<usize> = parse_seats(input).iter().map(|k| k.id()).collect();
+    ids.sort_unstable();
+    ids
+}
+
+/// Draw the plane as one line per row, front row first, and one column
+/// per seat. Occupied seats are '#' and empty seats '.'. Lines that
+/// can't be parsed are skipped.
+pub fn render_occupancy(input: &str) -> String {
+    let mut occupied = [false; 128 * 8];
+    for seat in parse_seats(input) {
+        occupied[seat.id()] = true;
+    }
+
+    occupied
+        .chunks(8)
+        .map(|row| {
+            row.iter()
+                .map(|taken| if *taken { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Find the id of the missing seat. Task tells that IDs -1 and +1 from
+/// our seat are on the list. Therefore we can loop once through the
+/// sorted ids and find where id_now - id_prev == 2. Our seat ID will be
+/// id_now - 1. Duplicate seats are dropped with a warning first.
+pub fn find_missing_seat(seats: &[PlaneSeat]) -> Option<usize> {
+    let unique: BTreeSet<usize> = seats.iter().map(|k| k.id()).collect();
+    if unique.len() < seats.len() {
+        warn!(
+            "Dropped {} duplicate seats from input data.",
+            seats.len() - unique.len()
+        );
+    }
+    let ids: Vec<usize> = unique.into_iter().collect();
+
+    ids.windows(2).find(|w| w[1] - w[0] == 2).map(|w| w[0] + 1)
+}
+
+/// Find the id of the missing seat without assuming the input has only
+/// one gap. Every id between the lowest and highest occupied seat that
+/// is not occupied is a gap. Returns the gap only if there is exactly
+/// one, so partial input data gives None instead of a wrong seat.
+pub fn missing_seat_by_set(input: &str) -> Option<usize> {
+    let occupied: HashSet<usize> = seat_ids(input).into_iter().collect();
+    let low = *occupied.iter().min()?;
+    let high = *occupied.iter().max()?;
+
+    let mut gaps = (low..=high).filter(|id| !occupied.contains(id));
+    match (gaps.next(), gaps.next()) {
+        (Some(id), None) => Some(id),
+        _ => None,
+    }
+}
+
+/// Day 5 for the common Solver interface. Part 2 is a text answer when
+/// no seat is missing, which is the case with the test data.
+pub struct Day05 {
+    seats: Vec<PlaneSeat>,
+}
+
+impl Solver for Day05 {
+    fn parse(input: &str) -> Day05 {
+        Day05 {
+            seats: parse_seats(input),
+        }
+    }
+
+    fn part1(&self) -> Answer {
+        match self.seats.iter().map(|k| k.id()).max() {
+            Some(id) => Answer::from(id),
+            None => Answer::from("No seats found in input data."),
+        }
+    }
+
+    fn part2(&self) -> Answer {
+        match find_missing_seat(&self.seats) {
+            Some(id) => Answer::from(id),
+            None => Answer::from("No missing seat found in input data."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod day_05 {
+    use super::*;
+
+    #[test]
+    fn seat_from_id() {
+        let seat = PlaneSeat::from_id(359).unwrap();
+        assert_eq!((seat.row(), seat.seat(), seat.id()), (44, 7, 359));
+        assert_eq!(PlaneSeat::from_id(820), PlaneSeat::new(102, 4));
+        assert_eq!(PlaneSeat::from_id(1024), None);
+    }
+
+    #[test]
+    fn decoders_agree() {
+        for line in get_input_test().lines() {
+            let a = string_to_planeseat(line.trim()).unwrap();
+            let b = decode_seat(line.trim()).unwrap();
+            assert_eq!(a, b);
+        }
+        assert!(decode_seat("FBFBBFFRL").is_none());
+        assert!(decode_seat("FBFBBFFRLF").is_none());
+    }
+
+    #[test]
+    fn strict_decode_errors() {
+        assert_eq!(try_decode_seat("FBFBBFFRLR").unwrap().id(), 357);
+        let e = try_decode_seat("FBFBBFFRL").unwrap_err();
+        assert_eq!(e.to_string(), "Seat \"FBFBBFFRL\" is too short.");
+        let e = try_decode_seat("FBFBBFFRLRR").unwrap_err();
+        assert_eq!(e.to_string(), "Seat \"FBFBBFFRLRR\" is too long.");
+        let e = try_decode_seat("FBFBBFFRLX").unwrap_err();
+        assert_eq!(e.to_string(), "Invalid character 'X' at position 9.");
+        let e = try_decode_seat(" FBFBBFFRLR").unwrap_err();
+        assert_eq!(e.to_string(), "Invalid character ' ' at position 0.");
+    }
+
+    #[test]
+    fn trailing_newline_is_ignored() {
+        let seats = parse_seats("FBFBBFFRLR\nBFFFBBFRRR\n\n");
+        let ids: Vec<usize> = seats.iter().map(|k| k.id()).collect();
+        assert_eq!(ids, vec![357, 567]);
+    }
+
+    #[test]
+    fn wrong_letter_at_position_is_skipped() {
+        for line in &["FFFFFFFFFF", "LBFBBFFRLR", "FBFBBFFRLB", "FBFBBFRRLR"] {
+            assert!(string_to_planeseat(line).is_none(), "{}", line);
+        }
+        let seats = parse_seats("FFFFFFFFFF\nLBFBBFFRLR\nFBFBBFFRLR");
+        assert_eq!(seats.iter().map(|k| k.id()).collect::<Vec<_>>(), vec![357]);
+    }
+
+    #[test]
+    fn seat_ids_sample() {
+        let ids = seat_ids(&get_input_test());
+        assert_eq!(ids, vec![119, 357, 567, 820]);
+        assert_eq!(ids.iter().max(), Some(&820));
+    }
+
+    #[test]
+    fn surrounding_whitespace() {
+        let seat = string_to_planeseat("  FBFBBFFRLR\r\n").unwrap();
+        assert_eq!((seat.row(), seat.seat(), seat.id()), (44, 5, 357));
+        assert!(string_to_planeseat("FBFBB FFRLR").is_none());
+    }
+
+    #[test]
+    fn occupancy_map() {
+        let map = render_occupancy(&get_input_test());
+        let rows: Vec<&str> = map.lines().collect();
+        assert_eq!(rows.len(), 128);
+        assert!(rows.iter().all(|row| row.len() == 8));
+        assert_eq!(map.matches('#').count(), 4);
+        // FBFBBFFRLR is row 44, seat 5.
+        assert_eq!(rows[44], ".....#..");
+    }
+
+    #[test]
+    fn boundary_seats() {
+        let first = string_to_planeseat("FFFFFFFLLL").unwrap();
+        assert_eq!((first.row(), first.seat(), first.id()), (0, 0, 0));
+        let last = string_to_planeseat("BBBBBBBRRR").unwrap();
+        assert_eq!((last.row(), last.seat(), last.id()), (127, 7, 1023));
+        assert_eq!(try_decode_seat("BBBBBBBRRR").unwrap().id(), 1023);
+    }
+
+    #[test]
+    fn seat_id_range() {
+        assert_eq!(get_seat_id(127, 7), Some(1023));
+        assert_eq!(get_seat_id(128, 0), None);
+        assert_eq!(get_seat_id(0, 8), None);
+        assert!(PlaneSeat::new(200, 0).is_none());
+    }
+
+    #[test]
+    fn seats_ordered_by_id() {
+        let front = PlaneSeat::new(10, 7).unwrap();
+        let back = PlaneSeat::new(11, 0).unwrap();
+        assert_eq!((front.id(), back.id()), (87, 88));
+        assert!(front < back);
+        let mut seats = [back, front];
+        seats.sort();
+        assert_eq!(seats[0].id(), 87);
+    }
+
+    #[test]
+    fn missing_seat_is_found() {
+        let seats: Vec<PlaneSeat> = (10..20)
+            .rev()
+            .filter(|id| *id != 14)
+            .filter_map(|id| PlaneSeat::new(id / 8, id % 8))
+            .collect();
+        assert_eq!(find_missing_seat(&seats), Some(14));
+        assert_eq!(find_missing_seat(&seats[..3]), None);
+    }
+
+    fn seats_to_input(ids: &[usize]) -> String {
+        ids.iter()
+            .map(|id| {
+                let row: String = (0..7)
+                    .map(|bit| if id >> (9 - bit) & 1 == 1 { 'B' } else { 'F' })
+                    .collect();
+                let seat: String = (0..3)
+                    .map(|bit| if id >> (2 - bit) & 1 == 1 { 'R' } else { 'L' })
+                    .collect();
+                row + &seat
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn missing_seat_by_set_single_gap() {
+        let ids: Vec<usize> = (10..20).filter(|id| *id != 14).collect();
+        let input = seats_to_input(&ids);
+        assert_eq!(seat_ids(&input), ids);
+        assert_eq!(missing_seat_by_set(&input), Some(14));
+    }
+
+    #[test]
+    fn missing_seat_by_set_many_gaps() {
+        let ids: Vec<usize> = (10..20).filter(|id| *id != 14 && *id != 17).collect();
+        assert_eq!(missing_seat_by_set(&seats_to_input(&ids)), None);
+        assert_eq!(missing_seat_by_set(&seats_to_input(&[10, 11, 12])), None);
+        assert_eq!(missing_seat_by_set(""), None);
+    }
+
+    #[test]
+    fn duplicate_seats_are_dropped() {
+        let mut seats: Vec<PlaneSeat> = [10, 11, 12, 12, 13, 15, 15, 16]
+            .iter()
+            .filter_map(|id| PlaneSeat::new(id / 8, id % 8))
+            .collect();
+        assert_eq!(find_missing_seat(&seats), Some(14));
+        // Duplicate of the seat before the gap.
+        seats.push(PlaneSeat::new(1, 5).unwrap());
+        assert_eq!(find_missing_seat(&seats), Some(14));
+    }
+}